@@ -2116,23 +2116,31 @@ pub async fn run_tool_call_loop(
             progress_indices.push(progress_idx);
         }
 
-        let executed_outcomes = if allow_parallel_execution && executable_calls.len() > 1 {
-            execute_tools_parallel(
-                &executable_calls,
-                tools_registry,
-                observer,
-                cancellation_token.as_ref(),
-            )
-            .await?
-        } else {
-            execute_tools_sequential(
-                &executable_calls,
-                tools_registry,
-                observer,
-                cancellation_token.as_ref(),
-            )
-            .await?
+        let audit_context = crate::security::audit::CommandAuditContext {
+            channel: channel_name.to_string(),
+            correlation_id: turn_id.clone(),
         };
+        let executed_outcomes =
+            crate::security::audit::scope_command_context(audit_context, async {
+                if allow_parallel_execution && executable_calls.len() > 1 {
+                    execute_tools_parallel(
+                        &executable_calls,
+                        tools_registry,
+                        observer,
+                        cancellation_token.as_ref(),
+                    )
+                    .await
+                } else {
+                    execute_tools_sequential(
+                        &executable_calls,
+                        tools_registry,
+                        observer,
+                        cancellation_token.as_ref(),
+                    )
+                    .await
+                }
+            })
+            .await?;
 
         for (((idx, call), mut outcome), progress_idx) in executable_indices
             .iter()
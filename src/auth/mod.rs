@@ -359,10 +359,7 @@ pub fn normalize_provider(provider: &str) -> Result<String> {
 }
 
 pub fn state_dir_from_config(config: &Config) -> PathBuf {
-    config
-        .config_path
-        .parent()
-        .map_or_else(|| PathBuf::from("."), PathBuf::from)
+    config.zeroclaw_dir()
 }
 
 pub fn default_profile_id(provider: &str) -> String {
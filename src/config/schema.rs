@@ -144,6 +144,8 @@ const SUPPORTED_PROXY_SERVICE_KEYS: &[&str] = &[
     "memory.embeddings",
     "tunnel.custom",
     "transcription.groq",
+    "security.audit_remote",
+    "security.audit_alerts",
 ];
 
 const SUPPORTED_PROXY_SERVICE_SELECTORS: &[&str] = &[
@@ -1755,6 +1757,38 @@ pub struct GatewayConfig {
     /// Node-control protocol scaffold (`[gateway.node_control]`).
     #[serde(default)]
     pub node_control: NodeControlConfig,
+
+    /// Also serve the gateway on this Unix domain socket path (Unix only).
+    /// Access is controlled by filesystem permissions; socket clients are
+    /// treated as loopback.
+    #[serde(default)]
+    pub unix_socket: Option<String>,
+
+    /// Permission bits of `unix_socket`, set before the socket is reachable
+    /// (default: 0o600).
+    #[serde(default = "default_gateway_unix_socket_mode")]
+    pub unix_socket_mode: u32,
+
+    /// Seconds to let in-flight requests finish after a shutdown signal
+    /// before the gateway exits anyway (default: 30).
+    #[serde(default = "default_gateway_shutdown_timeout_secs")]
+    pub shutdown_timeout_secs: u64,
+
+    /// Source CIDRs allowed to reach the gateway. Empty allows any source
+    /// not matched by `ip_denylist`. The client IP honors
+    /// `trust_forwarded_headers`.
+    #[serde(default)]
+    pub ip_allowlist: Vec<String>,
+
+    /// Source CIDRs always rejected, checked before `ip_allowlist`.
+    #[serde(default)]
+    pub ip_denylist: Vec<String>,
+
+    /// Role (built-in or from `[[security.roles]]`) granted to clients of
+    /// `/api/events` and `/ws/events`. Audit entries are only streamed when
+    /// the role allows `audit_events` (default: `viewer`, which does not).
+    #[serde(default = "default_gateway_events_role")]
+    pub events_role: String,
 }
 
 /// Node-control scaffold settings under `[gateway.node_control]`.
@@ -1803,6 +1837,18 @@ fn default_gateway_idempotency_max_keys() -> usize {
     10_000
 }
 
+fn default_gateway_unix_socket_mode() -> u32 {
+    0o600
+}
+
+fn default_gateway_shutdown_timeout_secs() -> u64 {
+    30
+}
+
+fn default_gateway_events_role() -> String {
+    "viewer".into()
+}
+
 fn default_true() -> bool {
     true
 }
@@ -1822,6 +1868,12 @@ impl Default for GatewayConfig {
             idempotency_ttl_secs: default_idempotency_ttl_secs(),
             idempotency_max_keys: default_gateway_idempotency_max_keys(),
             node_control: NodeControlConfig::default(),
+            unix_socket: None,
+            unix_socket_mode: default_gateway_unix_socket_mode(),
+            shutdown_timeout_secs: default_gateway_shutdown_timeout_secs(),
+            ip_allowlist: Vec::new(),
+            ip_denylist: Vec::new(),
+            events_role: default_gateway_events_role(),
         }
     }
 }
@@ -2679,6 +2731,23 @@ fn validate_proxy_url(field: &str, url: &str) -> Result<()> {
     Ok(())
 }
 
+/// Audit data leaves the host only over TLS, except to a loopback collector.
+fn validate_audit_collector_url(field: &str, raw: &str) -> Result<()> {
+    let url =
+        reqwest::Url::parse(raw.trim()).with_context(|| format!("{field} must be a valid URL"))?;
+    let loopback = url.host_str().is_some_and(|host| {
+        host == "localhost"
+            || host
+                .trim_matches(['[', ']'])
+                .parse::<IpAddr>()
+                .is_ok_and(|ip| ip.is_loopback())
+    });
+    if url.scheme() != "https" && !(url.scheme() == "http" && loopback) {
+        anyhow::bail!("{field} must use https:// (http:// is allowed only for loopback)");
+    }
+    Ok(())
+}
+
 fn parse_cidr_notation(raw: &str) -> Result<(IpAddr, u8)> {
     let (ip_raw, prefix_raw) = raw
         .trim()
@@ -5918,11 +5987,12 @@ pub struct SecurityRoleConfig {
     #[serde(default)]
     pub description: String,
 
-    /// Explicit allowlist of tools for this role.
+    /// Explicit allowlist of tools for this role. `*` matches any run of
+    /// characters, e.g. `git*` or `cron_*`.
     #[serde(default)]
     pub allowed_tools: Vec<String>,
 
-    /// Explicit denylist of tools for this role.
+    /// Explicit denylist of tools for this role (same pattern syntax).
     #[serde(default)]
     pub denied_tools: Vec<String>,
 
@@ -6253,6 +6323,7 @@ impl Default for ResourceLimitsConfig {
 
 /// Audit logging configuration
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct AuditConfig {
     /// Enable audit logging
     #[serde(default = "default_audit_enabled")]
@@ -6262,13 +6333,391 @@ pub struct AuditConfig {
     #[serde(default = "default_audit_log_path")]
     pub log_path: String,
 
+    /// Storage backend for audit events
+    #[serde(default)]
+    pub backend: AuditBackend,
+
     /// Maximum log size in MB before rotation
     #[serde(default = "default_audit_max_size_mb")]
     pub max_size_mb: u32,
 
-    /// Sign events with HMAC for tamper evidence
+    /// Rotate once the oldest event in the active log is this many hours old
+    /// (0 = size-based rotation only)
+    #[serde(default)]
+    pub max_age_hours: u32,
+
+    /// Number of rotated log files to keep; older segments are deleted
+    #[serde(default = "default_audit_max_rotated_files")]
+    pub max_rotated_files: u32,
+
+    /// Compress segments that fall out of the `max_rotated_files` window
+    /// into `<log_path>.archive/` instead of deleting them
+    #[serde(default)]
+    pub archive_rotated: bool,
+
+    /// With the `sqlite` backend, delete events older than this many days
+    /// (0 = keep everything). The JSONL backend uses the rotation settings.
+    #[serde(default)]
+    pub retention_days: u32,
+
+    /// Sign each event with an Ed25519 key (`audit-signing.key` in the
+    /// zeroclaw dir) so exported evidence can be authenticated
     #[serde(default)]
     pub sign_events: bool,
+
+    /// Chain each event to the previous one by SHA-256 so edits and
+    /// deleted lines can be detected with `AuditLogger::verify_chain`
+    #[serde(default)]
+    pub hash_chain: bool,
+
+    /// Maximum bytes of stdout and of stderr stored per command event
+    /// (0 = do not store command output)
+    #[serde(default)]
+    pub capture_output_max_bytes: usize,
+
+    /// Snapshot the workspace around each audited command and record the
+    /// files it created, modified, or deleted
+    #[serde(default)]
+    pub track_file_changes: bool,
+
+    /// Maximum files scanned per workspace snapshot
+    #[serde(default = "default_audit_track_file_changes_max_files")]
+    pub track_file_changes_max_files: usize,
+
+    /// Most recent events kept in memory for fast `recent()` lookups; older
+    /// events are read back from storage (0 = no in-memory buffer)
+    #[serde(default = "default_audit_memory_buffer_events")]
+    pub memory_buffer_events: usize,
+
+    /// Redact known credentials and common secret patterns (API keys,
+    /// bearer tokens, passwords) from commands and output before writing
+    #[serde(default = "default_true")]
+    pub redact_secrets: bool,
+
+    /// Forward audit events to a remote collector
+    #[serde(default)]
+    pub remote: AuditRemoteConfig,
+
+    /// Send a copy of each audit event to syslog
+    #[serde(default)]
+    pub syslog: AuditSyslogConfig,
+
+    /// POST high-severity audit events to webhooks
+    #[serde(default)]
+    pub alerts: AuditAlertsConfig,
+
+    /// Also emit each event as a structured `tracing` event (target
+    /// `zeroclaw::audit`) so it lands in the runtime log with its span
+    #[serde(default)]
+    pub trace_events: bool,
+
+    /// Collapse bursts of identical events into a single summary
+    #[serde(default)]
+    pub dedup: AuditDedupConfig,
+
+    /// Rules applied when exporting anonymized audit data
+    #[serde(default)]
+    pub anonymize: AuditAnonymizeConfig,
+}
+
+/// Anonymized audit export (`[security.audit.anonymize]`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditAnonymizeConfig {
+    /// Key for deriving stable pseudonyms from user ids and usernames.
+    /// When unset, a random key is used per export, so pseudonyms are only
+    /// consistent within a single export.
+    #[serde(default)]
+    pub pseudonym_key: Option<String>,
+
+    /// Replace absolute and home-relative paths with `<path>`
+    #[serde(default = "default_true")]
+    pub strip_paths: bool,
+
+    /// Replace URL hosts, `user@host` hosts, and IPv4 addresses
+    #[serde(default = "default_true")]
+    pub strip_hostnames: bool,
+
+    /// Additional regex rules applied to commands, errors, output, and paths
+    #[serde(default)]
+    pub rules: Vec<AuditAnonymizeRule>,
+}
+
+impl Default for AuditAnonymizeConfig {
+    fn default() -> Self {
+        Self {
+            pseudonym_key: None,
+            strip_paths: true,
+            strip_hostnames: true,
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// Regex replacement applied during anonymized export.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditAnonymizeRule {
+    /// Regex to match (e.g. an internal domain like `corp\.example\.com`)
+    pub pattern: String,
+    /// Replacement text; may reference capture groups as `$1`
+    #[serde(default = "default_audit_anonymize_replacement")]
+    pub replacement: String,
+}
+
+fn default_audit_anonymize_replacement() -> String {
+    "<redacted>".into()
+}
+
+/// Audit event storage backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditBackend {
+    /// Append-only JSONL file at `log_path` with size/age rotation (default)
+    #[default]
+    Jsonl,
+    /// SQLite database next to `log_path` (`audit.db`), indexed for fast
+    /// queries over long histories and safe for concurrent writers
+    Sqlite,
+}
+
+/// Remote audit collector (`[security.audit.remote]`).
+///
+/// Events are spooled next to the audit log and shipped in batches by the
+/// daemon, so they survive collector outages and restarts.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditRemoteConfig {
+    /// Enable shipping audit events to `url`
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Collector endpoint; must be `https://` (plain `http://` only for loopback)
+    #[serde(default)]
+    pub url: String,
+
+    /// Optional bearer token sent as `Authorization: Bearer <token>`
+    #[serde(default)]
+    pub auth_token: Option<String>,
+
+    /// Maximum events per POST
+    #[serde(default = "default_audit_remote_batch_size")]
+    pub batch_size: usize,
+
+    /// Seconds between shipping attempts
+    #[serde(default = "default_audit_remote_flush_interval_secs")]
+    pub flush_interval_secs: u64,
+
+    /// Retries per batch after the first attempt before deferring it to the
+    /// next flush
+    #[serde(default = "default_audit_remote_max_retries")]
+    pub max_retries: u32,
+
+    /// Largest the pending-event spool may grow, in MB; new events are not
+    /// queued for shipping while it is full
+    #[serde(default = "default_audit_remote_max_spool_mb")]
+    pub max_spool_mb: u32,
+}
+
+/// Webhook alerts for high-severity audit events (`[security.audit.alerts]`).
+///
+/// Matching events are spooled next to the audit log and POSTed by the
+/// daemon; deliveries that exhaust their retries go to a dead-letter file.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditAlertsConfig {
+    /// Enable webhook alerts
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Lowest severity that triggers an alert
+    #[serde(default = "default_audit_alerts_min_severity")]
+    pub min_severity: crate::security::audit::AuditSeverity,
+
+    /// Webhook endpoints; each must be `https://` (plain `http://` only for loopback)
+    #[serde(default)]
+    pub webhook_urls: Vec<String>,
+
+    /// Key for the `X-Zeroclaw-Signature: sha256=<hex>` HMAC over each body
+    #[serde(default)]
+    pub hmac_secret: Option<String>,
+
+    /// Retries per webhook after the first attempt before the alert is
+    /// dead-lettered
+    #[serde(default = "default_audit_alerts_max_retries")]
+    pub max_retries: u32,
+
+    /// Seconds between checks for pending alerts
+    #[serde(default = "default_audit_alerts_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+
+    /// Largest the pending-alert spool may grow, in MB; new alerts are not
+    /// queued while it is full
+    #[serde(default = "default_audit_alerts_max_spool_mb")]
+    pub max_spool_mb: u32,
+}
+
+fn default_audit_alerts_min_severity() -> crate::security::audit::AuditSeverity {
+    crate::security::audit::AuditSeverity::Critical
+}
+
+fn default_audit_alerts_max_retries() -> u32 {
+    3
+}
+
+fn default_audit_alerts_poll_interval_secs() -> u64 {
+    5
+}
+
+fn default_audit_alerts_max_spool_mb() -> u32 {
+    16
+}
+
+impl Default for AuditAlertsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_severity: default_audit_alerts_min_severity(),
+            webhook_urls: Vec::new(),
+            hmac_secret: None,
+            max_retries: default_audit_alerts_max_retries(),
+            poll_interval_secs: default_audit_alerts_poll_interval_secs(),
+            max_spool_mb: default_audit_alerts_max_spool_mb(),
+        }
+    }
+}
+
+/// Deduplication of repeated audit events (`[security.audit.dedup]`).
+///
+/// The first occurrence of an event is written; identical events (same type,
+/// actor, action, and outcome) within the window are dropped and reported by
+/// one summary event carrying the suppressed count once the window ends.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditDedupConfig {
+    /// Enable deduplication
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Default window in seconds
+    #[serde(default = "default_audit_dedup_window_secs")]
+    pub window_secs: u64,
+
+    /// Per-event-type windows (e.g. `auth_failure = 300`); `0` disables
+    /// deduplication for that type
+    #[serde(default)]
+    pub event_type_windows: BTreeMap<String, u64>,
+}
+
+fn default_audit_dedup_window_secs() -> u64 {
+    60
+}
+
+impl Default for AuditDedupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_secs: default_audit_dedup_window_secs(),
+            event_type_windows: BTreeMap::new(),
+        }
+    }
+}
+
+/// Syslog output for audit events (`[security.audit.syslog]`).
+///
+/// Events are sent as RFC 5424 messages with facility `log audit` (13) and a
+/// severity derived from the event; the local audit log stays authoritative.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditSyslogConfig {
+    /// Send every audit event to `address`
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// `udp://host:port`, `tcp://host:port`, or `unix:///path/to/socket`
+    #[serde(default = "default_audit_syslog_address")]
+    pub address: String,
+
+    /// APP-NAME field of each message
+    #[serde(default = "default_audit_syslog_app_name")]
+    pub app_name: String,
+
+    /// Message body: the audit log's JSON line, or a CEF/LEEF rendering
+    #[serde(default)]
+    pub format: AuditEventFormat,
+
+    /// Lowest severity sent to syslog; less severe events stay in the local log only
+    #[serde(default = "default_audit_syslog_min_severity")]
+    pub min_severity: crate::security::audit::AuditSeverity,
+}
+
+fn default_audit_syslog_min_severity() -> crate::security::audit::AuditSeverity {
+    crate::security::audit::AuditSeverity::Info
+}
+
+/// Rendering of audit events for external sinks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditEventFormat {
+    /// Same JSON as an `audit.log` line (default)
+    #[default]
+    Json,
+    /// ArcSight Common Event Format
+    Cef,
+    /// QRadar Log Event Extended Format 1.0
+    Leef,
+}
+
+fn default_audit_syslog_address() -> String {
+    "udp://127.0.0.1:514".into()
+}
+
+fn default_audit_syslog_app_name() -> String {
+    "zeroclaw".into()
+}
+
+impl Default for AuditSyslogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            address: default_audit_syslog_address(),
+            app_name: default_audit_syslog_app_name(),
+            format: AuditEventFormat::default(),
+            min_severity: default_audit_syslog_min_severity(),
+        }
+    }
+}
+
+fn default_audit_memory_buffer_events() -> usize {
+    256
+}
+
+fn default_audit_track_file_changes_max_files() -> usize {
+    10_000
+}
+
+fn default_audit_remote_batch_size() -> usize {
+    100
+}
+
+fn default_audit_remote_flush_interval_secs() -> u64 {
+    10
+}
+
+fn default_audit_remote_max_retries() -> u32 {
+    3
+}
+
+fn default_audit_remote_max_spool_mb() -> u32 {
+    64
+}
+
+impl Default for AuditRemoteConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: String::new(),
+            auth_token: None,
+            batch_size: default_audit_remote_batch_size(),
+            flush_interval_secs: default_audit_remote_flush_interval_secs(),
+            max_retries: default_audit_remote_max_retries(),
+            max_spool_mb: default_audit_remote_max_spool_mb(),
+        }
+    }
 }
 
 fn default_audit_enabled() -> bool {
@@ -6283,13 +6732,34 @@ fn default_audit_max_size_mb() -> u32 {
     100
 }
 
+fn default_audit_max_rotated_files() -> u32 {
+    10
+}
+
 impl Default for AuditConfig {
     fn default() -> Self {
         Self {
             enabled: default_audit_enabled(),
             log_path: default_audit_log_path(),
+            backend: AuditBackend::default(),
             max_size_mb: default_audit_max_size_mb(),
+            max_age_hours: 0,
+            max_rotated_files: default_audit_max_rotated_files(),
+            archive_rotated: false,
+            retention_days: 0,
             sign_events: false,
+            hash_chain: false,
+            capture_output_max_bytes: 0,
+            track_file_changes: false,
+            track_file_changes_max_files: default_audit_track_file_changes_max_files(),
+            memory_buffer_events: default_audit_memory_buffer_events(),
+            redact_secrets: true,
+            remote: AuditRemoteConfig::default(),
+            syslog: AuditSyslogConfig::default(),
+            alerts: AuditAlertsConfig::default(),
+            trace_events: false,
+            dedup: AuditDedupConfig::default(),
+            anonymize: AuditAnonymizeConfig::default(),
         }
     }
 }
@@ -7611,6 +8081,21 @@ impl Config {
                 &mut config.gateway.paired_tokens,
                 "config.gateway.paired_tokens",
             )?;
+            decrypt_optional_secret(
+                &store,
+                &mut config.security.audit.remote.auth_token,
+                "config.security.audit.remote.auth_token",
+            )?;
+            decrypt_optional_secret(
+                &store,
+                &mut config.security.audit.alerts.hmac_secret,
+                "config.security.audit.alerts.hmac_secret",
+            )?;
+            decrypt_optional_secret(
+                &store,
+                &mut config.security.audit.anonymize.pseudonym_key,
+                "config.security.audit.anonymize.pseudonym_key",
+            )?;
 
             for agent in config.agents.values_mut() {
                 decrypt_optional_secret(&store, &mut agent.api_key, "config.agents.*.api_key")?;
@@ -7748,6 +8233,21 @@ impl Config {
         Self::normalize_provider_transport(self.provider.transport.as_deref(), "provider.transport")
     }
 
+    /// Directory holding `config.toml` and runtime state (audit log, spools,
+    /// daemon state). Every component must resolve state paths through this
+    /// so they agree on where files live.
+    pub fn zeroclaw_dir(&self) -> PathBuf {
+        self.zeroclaw_dir_or(|| PathBuf::from("."))
+    }
+
+    /// [`Self::zeroclaw_dir`], with `fallback` used when `config_path` has
+    /// no parent (e.g. a runtime's own storage path).
+    pub fn zeroclaw_dir_or(&self, fallback: impl FnOnce() -> PathBuf) -> PathBuf {
+        self.config_path
+            .parent()
+            .map_or_else(fallback, PathBuf::from)
+    }
+
     fn lookup_model_provider_profile(
         &self,
         provider_name: &str,
@@ -7881,6 +8381,16 @@ impl Config {
         if self.gateway.host.trim().is_empty() {
             anyhow::bail!("gateway.host must not be empty");
         }
+        for (field, cidrs) in [
+            ("ip_allowlist", &self.gateway.ip_allowlist),
+            ("ip_denylist", &self.gateway.ip_denylist),
+        ] {
+            for (i, cidr) in cidrs.iter().enumerate() {
+                parse_cidr_notation(cidr).with_context(|| {
+                    format!("gateway.{field}[{i}] is invalid CIDR notation: {cidr}")
+                })?;
+            }
+        }
 
         // Autonomy
         if self.autonomy.max_actions_per_hour == 0 {
@@ -8247,6 +8757,72 @@ impl Config {
                 );
             }
         }
+        let audit_remote = &self.security.audit.remote;
+        if audit_remote.enabled {
+            validate_audit_collector_url("security.audit.remote.url", &audit_remote.url)?;
+            if audit_remote.batch_size == 0 {
+                anyhow::bail!("security.audit.remote.batch_size must be greater than 0");
+            }
+            if audit_remote.flush_interval_secs == 0 {
+                anyhow::bail!("security.audit.remote.flush_interval_secs must be greater than 0");
+            }
+            if audit_remote.max_spool_mb == 0 {
+                anyhow::bail!("security.audit.remote.max_spool_mb must be greater than 0");
+            }
+        }
+        let audit_alerts = &self.security.audit.alerts;
+        if audit_alerts.enabled {
+            if audit_alerts.webhook_urls.is_empty() {
+                anyhow::bail!("security.audit.alerts.webhook_urls must not be empty when enabled");
+            }
+            for (i, url) in audit_alerts.webhook_urls.iter().enumerate() {
+                validate_audit_collector_url(
+                    &format!("security.audit.alerts.webhook_urls[{i}]"),
+                    url,
+                )?;
+            }
+            if audit_alerts
+                .hmac_secret
+                .as_deref()
+                .is_some_and(|secret| secret.trim().is_empty())
+            {
+                anyhow::bail!("security.audit.alerts.hmac_secret must not be empty when set");
+            }
+            if audit_alerts.poll_interval_secs == 0 {
+                anyhow::bail!("security.audit.alerts.poll_interval_secs must be greater than 0");
+            }
+            if audit_alerts.max_spool_mb == 0 {
+                anyhow::bail!("security.audit.alerts.max_spool_mb must be greater than 0");
+            }
+        }
+        let audit_syslog = &self.security.audit.syslog;
+        if audit_syslog.enabled {
+            crate::security::audit_syslog::SyslogSink::validate(audit_syslog)
+                .context("security.audit.syslog is invalid")?;
+        }
+        for event_type in self.security.audit.dedup.event_type_windows.keys() {
+            event_type
+                .parse::<crate::security::audit::AuditEventType>()
+                .context("security.audit.dedup.event_type_windows has an invalid key")?;
+        }
+        let audit_anonymize = &self.security.audit.anonymize;
+        if audit_anonymize
+            .pseudonym_key
+            .as_deref()
+            .is_some_and(|key| key.trim().is_empty())
+        {
+            anyhow::bail!("security.audit.anonymize.pseudonym_key must not be empty when set");
+        }
+        for (i, rule) in audit_anonymize.rules.iter().enumerate() {
+            if let Err(e) = regex::Regex::new(&rule.pattern) {
+                anyhow::bail!("security.audit.anonymize.rules[{i}].pattern is invalid: {e}");
+            }
+        }
+        if self.security.audit.track_file_changes
+            && self.security.audit.track_file_changes_max_files == 0
+        {
+            anyhow::bail!("security.audit.track_file_changes_max_files must be greater than 0");
+        }
         if self.security.perplexity_filter.perplexity_threshold <= 1.0 {
             anyhow::bail!(
                 "security.perplexity_filter.perplexity_threshold must be greater than 1.0"
@@ -9310,6 +9886,21 @@ impl Config {
             &mut config_to_save.gateway.paired_tokens,
             "config.gateway.paired_tokens",
         )?;
+        encrypt_optional_secret(
+            &store,
+            &mut config_to_save.security.audit.remote.auth_token,
+            "config.security.audit.remote.auth_token",
+        )?;
+        encrypt_optional_secret(
+            &store,
+            &mut config_to_save.security.audit.alerts.hmac_secret,
+            "config.security.audit.alerts.hmac_secret",
+        )?;
+        encrypt_optional_secret(
+            &store,
+            &mut config_to_save.security.audit.anonymize.pseudonym_key,
+            "config.security.audit.anonymize.pseudonym_key",
+        )?;
 
         for agent in config_to_save.agents.values_mut() {
             encrypt_optional_secret(&store, &mut agent.api_key, "config.agents.*.api_key")?;
@@ -9532,6 +10123,26 @@ mod tests {
         assert!(c.config_path.to_string_lossy().contains("config.toml"));
     }
 
+    #[test]
+    async fn zeroclaw_dir_falls_back_when_config_path_has_no_parent() {
+        let mut c = Config {
+            config_path: PathBuf::from("/srv/zeroclaw/config.toml"),
+            ..Config::default()
+        };
+        assert_eq!(c.zeroclaw_dir(), PathBuf::from("/srv/zeroclaw"));
+        assert_eq!(
+            c.zeroclaw_dir_or(|| PathBuf::from("/var/lib/zeroclaw")),
+            PathBuf::from("/srv/zeroclaw")
+        );
+
+        c.config_path = PathBuf::new();
+        assert_eq!(c.zeroclaw_dir(), PathBuf::from("."));
+        assert_eq!(
+            c.zeroclaw_dir_or(|| PathBuf::from("/var/lib/zeroclaw")),
+            PathBuf::from("/var/lib/zeroclaw")
+        );
+    }
+
     #[test]
     async fn wasm_config_default_has_correct_values() {
         let cfg = WasmConfig::default();
@@ -9842,6 +10453,45 @@ allowed_roots = []
             .contains("autonomy.non_cli_excluded_tools contains duplicate entry"));
     }
 
+    #[test]
+    async fn config_validate_rejects_invalid_audit_anonymize_rule() {
+        let mut cfg = Config::default();
+        cfg.security.audit.anonymize.rules = vec![AuditAnonymizeRule {
+            pattern: "([".into(),
+            replacement: "<redacted>".into(),
+        }];
+        let err = cfg.validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("security.audit.anonymize.rules[0].pattern is invalid"));
+    }
+
+    #[test]
+    async fn config_validate_rejects_plaintext_audit_alert_webhook() {
+        let mut cfg = Config::default();
+        cfg.security.audit.alerts.enabled = true;
+        cfg.security.audit.alerts.webhook_urls = vec!["http://alerts.example.com/hook".into()];
+        let err = cfg.validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("security.audit.alerts.webhook_urls[0] must use https://"));
+
+        cfg.security.audit.alerts.webhook_urls = vec!["http://127.0.0.1:9000/hook".into()];
+        assert!(cfg.validate().is_ok());
+    }
+
+    #[test]
+    async fn config_validate_rejects_unknown_audit_dedup_event_type() {
+        let mut cfg = Config::default();
+        cfg.security.audit.dedup.event_type_windows = [("auth_failure".to_string(), 300)].into();
+        assert!(cfg.validate().is_ok());
+
+        cfg.security.audit.dedup.event_type_windows =
+            [("encryption_failure".to_string(), 300)].into();
+        let err = cfg.validate().unwrap_err();
+        assert!(format!("{err:#}").contains("unknown audit event type: encryption_failure"));
+    }
+
     #[test]
     async fn runtime_config_default() {
         let r = RuntimeConfig::default();
@@ -10553,6 +11203,9 @@ tool_dispatcher = "xml"
         config.storage.provider.config.db_url = Some("postgres://user:pw@host/db".into());
         config.reliability.api_keys = vec!["backup-credential".into()];
         config.gateway.paired_tokens = vec!["zc_0123456789abcdef".into()];
+        config.security.audit.remote.auth_token = Some("audit-remote-credential".into());
+        config.security.audit.alerts.hmac_secret = Some("audit-alerts-credential".into());
+        config.security.audit.anonymize.pseudonym_key = Some("audit-pseudonym-credential".into());
         config.channels_config.telegram = Some(TelegramConfig {
             bot_token: "telegram-credential".into(),
             allowed_users: Vec::new(),
@@ -10691,6 +11344,17 @@ tool_dispatcher = "xml"
         assert!(crate::security::SecretStore::is_encrypted(paired_token));
         assert_eq!(store.decrypt(paired_token).unwrap(), "zc_0123456789abcdef");
 
+        let audit = &stored.security.audit;
+        for (stored_secret, expected) in [
+            (&audit.remote.auth_token, "audit-remote-credential"),
+            (&audit.alerts.hmac_secret, "audit-alerts-credential"),
+            (&audit.anonymize.pseudonym_key, "audit-pseudonym-credential"),
+        ] {
+            let encrypted = stored_secret.as_deref().unwrap();
+            assert!(crate::security::SecretStore::is_encrypted(encrypted));
+            assert_eq!(store.decrypt(encrypted).unwrap(), expected);
+        }
+
         let telegram_token = stored
             .channels_config
             .telegram
@@ -11513,6 +12177,12 @@ channel_id = "C123"
                 auth_token: Some("node-token".into()),
                 allowed_node_ids: vec!["node-1".into(), "node-2".into()],
             },
+            unix_socket: Some("/run/zeroclaw/gateway.sock".into()),
+            unix_socket_mode: 0o660,
+            shutdown_timeout_secs: 10,
+            ip_allowlist: vec!["10.0.0.0/8".into()],
+            ip_denylist: vec!["10.0.0.66/32".into()],
+            events_role: "admin".into(),
         };
         let toml_str = toml::to_string(&g).unwrap();
         let parsed: GatewayConfig = toml::from_str(&toml_str).unwrap();
@@ -11534,6 +12204,15 @@ channel_id = "C123"
             parsed.node_control.allowed_node_ids,
             vec!["node-1", "node-2"]
         );
+        assert_eq!(
+            parsed.unix_socket.as_deref(),
+            Some("/run/zeroclaw/gateway.sock")
+        );
+        assert_eq!(parsed.unix_socket_mode, 0o660);
+        assert_eq!(parsed.shutdown_timeout_secs, 10);
+        assert_eq!(parsed.ip_allowlist, vec!["10.0.0.0/8"]);
+        assert_eq!(parsed.ip_denylist, vec!["10.0.0.66/32"]);
+        assert_eq!(parsed.events_role, "admin");
     }
 
     #[test]
@@ -103,12 +103,55 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
         tracing::info!("Cron disabled; scheduler supervisor not started");
     }
 
+    if config.security.audit.enabled && config.security.audit.remote.enabled {
+        let audit_cfg = config.security.audit.clone();
+        let zeroclaw_dir = config.zeroclaw_dir();
+        handles.push(spawn_component_supervisor(
+            "audit_shipper",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let shipper =
+                    crate::security::audit_shipper::AuditShipper::new(&audit_cfg, &zeroclaw_dir);
+                async move { shipper.run().await }
+            },
+        ));
+    }
+
+    if config.security.audit.enabled && config.security.audit.alerts.enabled {
+        let audit_cfg = config.security.audit.clone();
+        let zeroclaw_dir = config.zeroclaw_dir();
+        handles.push(spawn_component_supervisor(
+            "audit_alerts",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let alerter =
+                    crate::security::audit_alerts::AuditAlerter::new(&audit_cfg, &zeroclaw_dir);
+                async move { alerter.run().await }
+            },
+        ));
+    }
+
+    if config.security.audit.enabled && config.security.audit.dedup.enabled {
+        let audit_config = config.clone();
+        handles.push(spawn_component_supervisor(
+            "audit_dedup",
+            initial_backoff,
+            max_backoff,
+            move || {
+                let logger = crate::security::AuditLogger::shared(&audit_config);
+                async move { logger?.run_dedup_sweeper().await }
+            },
+        ));
+    }
+
     println!("🧠 ZeroClaw daemon started");
     println!("   Gateway:  http://{host}:{port}");
     println!("   Components: gateway, channels, heartbeat, scheduler");
     println!("   Ctrl+C to stop");
 
-    tokio::signal::ctrl_c().await?;
+    crate::gateway::shutdown_signal().await;
     crate::health::mark_component_error("daemon", "shutdown requested");
 
     for handle in &handles {
@@ -122,11 +165,7 @@ pub async fn run(config: Config, host: String, port: u16) -> Result<()> {
 }
 
 pub fn state_file_path(config: &Config) -> PathBuf {
-    config
-        .config_path
-        .parent()
-        .map_or_else(|| PathBuf::from("."), PathBuf::from)
-        .join("daemon_state.json")
+    config.zeroclaw_dir().join("daemon_state.json")
 }
 
 fn spawn_state_writer(config: Config) -> JoinHandle<()> {
@@ -4,11 +4,12 @@
 
 use super::AppState;
 use axum::{
-    extract::{Path, Query, State},
+    extract::{ConnectInfo, Path, Query, State},
     http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Json},
 };
 use serde::Deserialize;
+use std::net::SocketAddr;
 
 const MASKED_SECRET: &str = "***MASKED***";
 
@@ -23,8 +24,11 @@ fn extract_bearer_token(headers: &HeaderMap) -> Option<&str> {
 }
 
 /// Verify bearer token against PairingGuard. Returns error response if unauthorized.
+/// Failures are audited under the client's address so repeated attempts from
+/// one client collapse under audit deduplication.
 fn require_auth(
     state: &AppState,
+    peer_addr: SocketAddr,
     headers: &HeaderMap,
 ) -> Result<(), (StatusCode, Json<serde_json::Value>)> {
     if !state.pairing.require_pairing() {
@@ -35,6 +39,15 @@ fn require_auth(
     if state.pairing.is_authenticated(token) {
         Ok(())
     } else {
+        let client =
+            super::client_key_from_request(Some(peer_addr), headers, state.trust_forwarded_headers);
+        super::audit_auth_event(
+            state,
+            headers,
+            false,
+            &client,
+            "missing or invalid bearer token",
+        );
         Err((
             StatusCode::UNAUTHORIZED,
             Json(serde_json::json!({
@@ -66,14 +79,29 @@ pub struct CronAddBody {
     pub command: String,
 }
 
+#[derive(Deserialize)]
+pub struct AuditLogQuery {
+    pub user: Option<String>,
+    pub hours: Option<u32>,
+    pub exit_code: Option<i32>,
+    pub command: Option<String>,
+    pub correlation_id: Option<String>,
+    pub event_type: Option<crate::security::audit::AuditEventType>,
+    pub severity: Option<crate::security::audit::AuditSeverity>,
+    pub channel: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
 // ── Handlers ────────────────────────────────────────────────────
 
 /// GET /api/status — system status overview
 pub async fn handle_api_status(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -105,9 +133,10 @@ pub async fn handle_api_status(
 /// GET /api/config — current config (api_key masked)
 pub async fn handle_api_config_get(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -136,10 +165,11 @@ pub async fn handle_api_config_get(
 /// PUT /api/config — update config from TOML body
 pub async fn handle_api_config_put(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     body: String,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -195,9 +225,10 @@ pub async fn handle_api_config_put(
 /// GET /api/tools — list registered tool specs
 pub async fn handle_api_tools(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -219,9 +250,10 @@ pub async fn handle_api_tools(
 /// GET /api/cron — list cron jobs
 pub async fn handle_api_cron_list(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -255,10 +287,11 @@ pub async fn handle_api_cron_list(
 /// POST /api/cron — add a new cron job
 pub async fn handle_api_cron_add(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     Json(body): Json<CronAddBody>,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -290,10 +323,11 @@ pub async fn handle_api_cron_add(
 /// DELETE /api/cron/:id — remove a cron job
 pub async fn handle_api_cron_delete(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     Path(id): Path<String>,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -311,9 +345,10 @@ pub async fn handle_api_cron_delete(
 /// GET /api/integrations — list all integrations with status
 pub async fn handle_api_integrations(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -339,9 +374,10 @@ pub async fn handle_api_integrations(
 /// POST /api/doctor — run diagnostics
 pub async fn handle_api_doctor(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -375,10 +411,11 @@ pub async fn handle_api_doctor(
 /// GET /api/memory — list or search memory entries
 pub async fn handle_api_memory_list(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     Query(params): Query<MemoryQuery>,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -415,10 +452,11 @@ pub async fn handle_api_memory_list(
 /// POST /api/memory — store a memory entry
 pub async fn handle_api_memory_store(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     Json(body): Json<MemoryStoreBody>,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -450,10 +488,11 @@ pub async fn handle_api_memory_store(
 /// DELETE /api/memory/:key — delete a memory entry
 pub async fn handle_api_memory_delete(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     Path(key): Path<String>,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -472,9 +511,10 @@ pub async fn handle_api_memory_delete(
 /// GET /api/cost — cost summary
 pub async fn handle_api_cost(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -505,9 +545,10 @@ pub async fn handle_api_cost(
 /// GET /api/cli-tools — discovered CLI tools
 pub async fn handle_api_cli_tools(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -519,9 +560,10 @@ pub async fn handle_api_cli_tools(
 /// GET /api/health — component health snapshot
 pub async fn handle_api_health(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -532,9 +574,10 @@ pub async fn handle_api_health(
 /// GET /api/pairing/devices — list paired devices
 pub async fn handle_api_pairing_devices(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -545,10 +588,11 @@ pub async fn handle_api_pairing_devices(
 /// DELETE /api/pairing/devices/:id — revoke paired device
 pub async fn handle_api_pairing_device_revoke(
     State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     Path(id): Path<String>,
 ) -> impl IntoResponse {
-    if let Err(e) = require_auth(&state, &headers) {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
         return e.into_response();
     }
 
@@ -571,6 +615,61 @@ pub async fn handle_api_pairing_device_revoke(
     Json(serde_json::json!({"status": "ok", "revoked": true, "id": id})).into_response()
 }
 
+/// GET /api/audit — query the security audit log
+pub async fn handle_api_audit(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Query(params): Query<AuditLogQuery>,
+) -> impl IntoResponse {
+    if let Err(e) = require_auth(&state, peer_addr, &headers) {
+        return e.into_response();
+    }
+
+    let Some(audit) = state.audit.clone() else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "Audit logging is disabled"})),
+        )
+            .into_response();
+    };
+
+    let query = crate::security::audit::AuditQuery {
+        since: params
+            .hours
+            .map(|hours| chrono::Utc::now() - chrono::Duration::hours(i64::from(hours))),
+        user: params.user,
+        exit_code: params.exit_code,
+        command_contains: params.command,
+        correlation_id: params.correlation_id,
+        event_type: params.event_type,
+        min_severity: params.severity,
+        channel: params.channel,
+        offset: params.offset.unwrap_or(0),
+        limit: Some(params.limit.unwrap_or(100).min(1000)),
+        ..crate::security::audit::AuditQuery::default()
+    };
+    let (offset, limit) = (query.offset, query.limit.unwrap_or_default());
+    // Queries may scan rotated and archived segments; keep them off the runtime.
+    match tokio::task::spawn_blocking(move || audit.query(&query)).await {
+        Ok(Ok(events)) => {
+            // A full page means there may be more; hand back the next offset.
+            let next_offset = (limit > 0 && events.len() == limit).then(|| offset + limit);
+            Json(serde_json::json!({"events": events, "next_offset": next_offset})).into_response()
+        }
+        Ok(Err(e)) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Audit query failed: {e}")})),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": format!("Audit query task failed: {e}")})),
+        )
+            .into_response(),
+    }
+}
+
 // ── Helpers ─────────────────────────────────────────────────────
 
 fn normalize_dashboard_config_toml(root: &mut toml::Value) {
@@ -23,6 +23,7 @@ use crate::cost::CostTracker;
 use crate::memory::{self, Memory, MemoryCategory};
 use crate::providers::{self, ChatMessage, Provider};
 use crate::runtime;
+use crate::security::audit::{AuditEvent, AuditEventType, AuditLogger};
 use crate::security::pairing::{constant_time_eq, is_public_bind, PairingGuard};
 use crate::security::SecurityPolicy;
 use crate::tools::traits::ToolSpec;
@@ -39,7 +40,7 @@ use axum::{
 };
 use futures_util::StreamExt;
 use parking_lot::Mutex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -57,6 +58,12 @@ pub const RATE_LIMIT_WINDOW_SECS: u64 = 60;
 pub const RATE_LIMIT_MAX_KEYS_DEFAULT: usize = 10_000;
 /// Fallback max distinct idempotency keys retained in gateway memory.
 pub const IDEMPOTENCY_MAX_KEYS_DEFAULT: usize = 10_000;
+/// Request/response header carrying the server-generated correlation id.
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+/// Response header echoing a well-formed `X-Correlation-Id` sent by the client.
+pub const CLIENT_CORRELATION_ID_HEADER: &str = "x-client-correlation-id";
+/// Longest client-supplied correlation id echoed; longer ones are dropped.
+const MAX_CORRELATION_ID_LEN: usize = 128;
 
 fn webhook_memory_key() -> String {
     format!("webhook_msg_{}", Uuid::new_v4())
@@ -285,29 +292,22 @@ pub(crate) fn client_key_from_request(
     headers: &HeaderMap,
     trust_forwarded_headers: bool,
 ) -> String {
-    if trust_forwarded_headers {
-        if let Some(ip) = forwarded_client_ip(headers) {
-            return ip.to_string();
-        }
-    }
-
-    peer_addr
-        .map(|addr| addr.ip().to_string())
-        .unwrap_or_else(|| "unknown".to_string())
+    request_ip_from_request(peer_addr, headers, trust_forwarded_headers)
+        .map_or_else(|| "unknown".to_string(), |ip| ip.to_string())
 }
 
+/// The client's IP, with IPv4-mapped IPv6 addresses (how IPv4 clients
+/// arrive on a dual-stack `[::]` bind) converted back to IPv4.
 fn request_ip_from_request(
     peer_addr: Option<SocketAddr>,
     headers: &HeaderMap,
     trust_forwarded_headers: bool,
 ) -> Option<IpAddr> {
-    if trust_forwarded_headers {
-        if let Some(ip) = forwarded_client_ip(headers) {
-            return Some(ip);
-        }
-    }
-
-    peer_addr.map(|addr| addr.ip())
+    trust_forwarded_headers
+        .then(|| forwarded_client_ip(headers))
+        .flatten()
+        .or_else(|| peer_addr.map(|addr| addr.ip()))
+        .map(|ip| ip.to_canonical())
 }
 
 fn is_loopback_request(
@@ -373,6 +373,53 @@ pub struct AppState {
     pub cost_tracker: Option<Arc<CostTracker>>,
     /// SSE broadcast channel for real-time events
     pub event_tx: tokio::sync::broadcast::Sender<serde_json::Value>,
+    /// Security audit log for pairing and authentication events
+    pub audit: Option<Arc<AuditLogger>>,
+}
+
+/// Source-IP allow/deny lists from `[gateway]`, parsed once at startup.
+#[derive(Debug, Default)]
+pub(super) struct IpFilter {
+    allow: Vec<(IpAddr, u8)>,
+    deny: Vec<(IpAddr, u8)>,
+}
+
+impl IpFilter {
+    pub(super) fn from_config(config: &crate::config::GatewayConfig) -> Result<Self> {
+        let parse = |field: &str, cidrs: &[String]| {
+            cidrs
+                .iter()
+                .map(|cidr| {
+                    crate::tools::url_validation::parse_cidr(cidr)
+                        .with_context(|| format!("gateway.{field} has invalid CIDR: {cidr}"))
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        Ok(Self {
+            allow: parse("ip_allowlist", &config.ip_allowlist)?,
+            deny: parse("ip_denylist", &config.ip_denylist)?,
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+
+    /// Deny entries win; a non-empty allowlist must then match. An unknown
+    /// client IP passes only when no allowlist is configured. IPv4-mapped
+    /// IPv6 addresses are matched as IPv4.
+    pub(super) fn permits(&self, ip: Option<IpAddr>) -> bool {
+        let contains = |cidrs: &[(IpAddr, u8)], ip: IpAddr| {
+            cidrs
+                .iter()
+                .any(|cidr| crate::tools::url_validation::cidr_contains_ip(*cidr, ip))
+        };
+        match ip.map(|ip| ip.to_canonical()) {
+            Some(ip) if contains(&self.deny, ip) => false,
+            Some(ip) => self.allow.is_empty() || contains(&self.allow, ip),
+            None => self.allow.is_empty(),
+        }
+    }
 }
 
 /// Run the HTTP gateway using axum with proper HTTP/1.1 compliance.
@@ -400,6 +447,15 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
     let listener = tokio::net::TcpListener::bind(addr).await?;
     let actual_port = listener.local_addr()?.port();
     let display_addr = format!("{host}:{actual_port}");
+    let (unix_listener, _unix_socket_file) = match config.gateway.unix_socket.as_deref() {
+        Some(path) => {
+            let (listener, socket_file) =
+                bind_unix_socket(std::path::Path::new(path), config.gateway.unix_socket_mode)?;
+            (Some(listener), Some(socket_file))
+        }
+        None => (None, None),
+    };
+    let drain_timeout = Duration::from_secs(config.gateway.shutdown_timeout_secs);
 
     let provider: Arc<dyn Provider> = Arc::from(providers::create_resilient_provider_with_options(
         config.default_provider.as_deref().unwrap_or("openrouter"),
@@ -479,8 +535,14 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         None
     };
 
+    // Audit logger (optional)
+    let audit = AuditLogger::shared_if_enabled(&config);
+
     // SSE broadcast channel for real-time events
     let (event_tx, _event_rx) = tokio::sync::broadcast::channel::<serde_json::Value>(256);
+    if let Some(audit) = audit.as_ref() {
+        spawn_audit_event_forwarder(audit.subscribe(), event_tx.clone());
+    }
     // Extract webhook secret for authentication
     let webhook_secret_hash: Option<Arc<str>> =
         config.channels_config.webhook.as_ref().and_then(|webhook| {
@@ -691,6 +753,9 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
     }
 
     println!("🦀 ZeroClaw Gateway listening on http://{display_addr}");
+    if let Some(path) = config.gateway.unix_socket.as_deref() {
+        println!("  🔌 Unix socket: {path}");
+    }
     if let Some(ref url) = tunnel_url {
         println!("  🌐 Public URL: {url}");
     }
@@ -764,6 +829,8 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         sse::BroadcastObserver::new(Box::new(bridged_observer), event_tx.clone()),
     );
 
+    let ip_filter = Arc::new(IpFilter::from_config(&config.gateway)?);
+
     let state = AppState {
         config: config_state,
         provider,
@@ -795,6 +862,7 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         max_tool_iterations,
         cost_tracker,
         event_tx,
+        audit,
     };
 
     // Config PUT needs larger body limit (1MB)
@@ -823,6 +891,8 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
     let app = Router::new()
         // ── Existing routes ──
         .route("/health", get(handle_health))
+        .route("/healthz", get(handle_healthz))
+        .route("/readyz", get(handle_readyz))
         .route("/metrics", get(handle_metrics))
         .route("/pair", post(handle_pair))
         .route("/webhook", get(handle_webhook_usage).post(handle_webhook))
@@ -860,6 +930,7 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
             "/api/pairing/devices/{id}",
             delete(api::handle_api_pairing_device_revoke),
         )
+        .route("/api/audit", get(api::handle_api_audit))
         .route("/api/cost", get(api::handle_api_cost))
         .route("/api/cli-tools", get(api::handle_api_cli_tools))
         .route("/api/health", get(api::handle_api_health))
@@ -868,11 +939,12 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         .route("/api/events", get(sse::handle_sse_events))
         // ── WebSocket agent chat ──
         .route("/ws/chat", get(ws::handle_ws_chat))
+        .route("/ws/events", get(ws::handle_ws_events))
         // ── Static assets (web dashboard) ──
         .route("/_app/{*path}", get(static_files::handle_static))
         // ── Config PUT with larger body limit ──
         .merge(config_put_router)
-        .with_state(state)
+        .with_state(state.clone())
         .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE))
         .layer(TimeoutLayer::with_status_code(
             StatusCode::REQUEST_TIMEOUT,
@@ -880,13 +952,63 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         ))
         // ── SPA fallback: non-API GET requests serve index.html ──
         .fallback(get(static_files::handle_spa_fallback));
+    let app = if ip_filter.is_empty() {
+        app
+    } else {
+        app.layer(axum::middleware::from_fn_with_state(
+            (ip_filter, state),
+            filter_source_ip,
+        ))
+    }
+    .layer(axum::middleware::from_fn(trace_request));
 
-    // Run the server
-    let serve_result = axum::serve(
+    // Run the server until a shutdown signal, then stop accepting and let
+    // in-flight requests finish within `shutdown_timeout_secs`.
+    let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        let _ = shutdown_tx.send(true);
+    });
+    let tcp_server = axum::serve(
         listener,
-        app.into_make_service_with_connect_info::<SocketAddr>(),
+        app.clone()
+            .into_make_service_with_connect_info::<SocketAddr>(),
     )
-    .await;
+    .with_graceful_shutdown(shutdown_requested(shutdown_rx.clone()));
+    #[cfg(unix)]
+    let unix_shutdown_rx = shutdown_rx.clone();
+    let serving = async move {
+        match unix_listener {
+            #[cfg(unix)]
+            Some(unix_listener) => {
+                // Socket peers have no IP; handlers see them as loopback.
+                let unix_app = app
+                    .layer(axum::Extension(UnixSocketClient))
+                    .layer(axum::Extension(ConnectInfo(SocketAddr::from((
+                        [127, 0, 0, 1],
+                        0,
+                    )))));
+                let unix_server = axum::serve(unix_listener, unix_app)
+                    .with_graceful_shutdown(shutdown_requested(unix_shutdown_rx));
+                tokio::try_join!(async { tcp_server.await }, async { unix_server.await })
+                    .map(|_| ())
+            }
+            _ => tcp_server.await,
+        }
+    };
+    let serve_result = tokio::select! {
+        result = serving => result,
+        () = async {
+            shutdown_requested(shutdown_rx).await;
+            tokio::time::sleep(drain_timeout).await;
+        } => {
+            tracing::warn!(
+                "Gateway shutdown deadline ({}s) reached; dropping in-flight requests",
+                drain_timeout.as_secs()
+            );
+            Ok(())
+        }
+    };
 
     if let Some(ref hooks) = hooks {
         hooks.fire_gateway_stop().await;
@@ -912,6 +1034,46 @@ async fn handle_health(State(state): State<AppState>) -> impl IntoResponse {
     Json(body)
 }
 
+/// GET /healthz — liveness probe; answers as long as the process serves HTTP
+async fn handle_healthz() -> impl IntoResponse {
+    Json(serde_json::json!({ "status": "ok" }))
+}
+
+/// GET /readyz — readiness probe with per-dependency status (public, no secrets)
+async fn handle_readyz(State(state): State<AppState>) -> impl IntoResponse {
+    let mut checks = serde_json::Map::new();
+    let mut ready = true;
+    let mut check = |name: &str, ok: bool, detail: String| {
+        ready &= ok;
+        checks.insert(
+            name.to_string(),
+            serde_json::json!({ "ok": ok, "detail": detail }),
+        );
+    };
+
+    let memory_ok = state.mem.health_check().await;
+    check("memory", memory_ok, format!("{} backend", state.mem.name()));
+
+    let audit_enabled = state.config.lock().security.audit.enabled;
+    let (audit_ok, audit_detail) = match (audit_enabled, state.audit.is_some()) {
+        (false, _) => (true, "disabled"),
+        (true, true) => (true, "logger initialized"),
+        (true, false) => (false, "enabled but logger failed to initialize"),
+    };
+    check("audit", audit_ok, audit_detail.to_string());
+
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let body = serde_json::json!({
+        "status": if ready { "ready" } else { "not_ready" },
+        "checks": checks,
+    });
+    (status, Json(body))
+}
+
 /// Prometheus content type for text exposition format.
 const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
 
@@ -964,6 +1126,332 @@ async fn handle_metrics(
     )
 }
 
+/// Permission a role's tool rules must allow for its event-stream clients to
+/// receive audit entries (see `gateway.events_role`).
+const AUDIT_EVENTS_PERMISSION: &str = "audit_events";
+
+/// Which event-bus payloads an `/api/events` or `/ws/events` client receives.
+#[derive(Debug, Clone, Default)]
+pub(super) struct EventFilter {
+    /// Event `type` values to forward; empty forwards every type.
+    types: HashSet<String>,
+    /// Whether `{"type":"audit"}` entries may be forwarded at all.
+    audit_allowed: bool,
+}
+
+impl EventFilter {
+    /// Filter for a client subscribed to the comma-separated event `types`
+    /// (`None` subscribes to everything), with audit entries gated on the
+    /// role in `gateway.events_role`.
+    pub(super) fn new(config: &Config, types: Option<&str>) -> Self {
+        let audit_allowed = match crate::security::RoleRegistry::from_config(&config.security.roles)
+        {
+            Ok(roles) => {
+                roles
+                    .resolve_tool_access(&config.gateway.events_role, AUDIT_EVENTS_PERMISSION, &[])
+                    .allowed
+            }
+            Err(e) => {
+                tracing::warn!("Invalid security.roles; withholding audit events: {e:#}");
+                false
+            }
+        };
+        let types = types
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|kind| !kind.is_empty())
+            .map(str::to_string)
+            .collect();
+        Self {
+            types,
+            audit_allowed,
+        }
+    }
+
+    pub(super) fn permits(&self, event: &serde_json::Value) -> bool {
+        let kind = event
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default();
+        if kind == "audit" && !self.audit_allowed {
+            return false;
+        }
+        self.types.is_empty() || self.types.contains(kind)
+    }
+}
+
+/// Republish audit entries on the gateway event bus (`/api/events`, `/ws/events`).
+fn spawn_audit_event_forwarder(
+    mut audit_rx: tokio::sync::broadcast::Receiver<crate::security::audit::AuditEvent>,
+    event_tx: tokio::sync::broadcast::Sender<serde_json::Value>,
+) {
+    tokio::spawn(async move {
+        loop {
+            match audit_rx.recv().await {
+                Ok(event) => {
+                    let _ = event_tx.send(serde_json::json!({
+                        "type": "audit",
+                        "event": event,
+                        "timestamp": event.timestamp.to_rfc3339(),
+                    }));
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Audit event stream lagged; skipped {skipped} events");
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+/// Wrap each request in a `gateway.request` span tagged with its correlation
+/// id, so logs and OTLP traces from handlers, tools, and audit writes can be
+/// joined. The id is always generated here and echoed on the response; a
+/// client's own `X-Correlation-Id` never becomes the audit correlation id, so
+/// it cannot splice events into another request's trace. A well-formed client
+/// value is recorded on the span and echoed as `X-Client-Correlation-Id`.
+async fn trace_request(
+    mut request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> Response {
+    let client_correlation_id = request
+        .headers_mut()
+        .remove(CORRELATION_ID_HEADER)
+        .filter(|value| value.to_str().is_ok_and(is_valid_correlation_id));
+    let correlation_id = Uuid::new_v4().to_string();
+    let span = tracing::info_span!(
+        "gateway.request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        correlation_id = %correlation_id,
+        client_correlation_id = tracing::field::Empty,
+    );
+    if let Some(client_id) = client_correlation_id
+        .as_ref()
+        .and_then(|value| value.to_str().ok())
+    {
+        span.record("client_correlation_id", client_id);
+    }
+    let value = header::HeaderValue::from_str(&correlation_id).ok();
+    if let Some(value) = value.clone() {
+        request.headers_mut().insert(CORRELATION_ID_HEADER, value);
+    }
+    let mut response = tracing::Instrument::instrument(next.run(request), span).await;
+    if let Some(value) = value {
+        response.headers_mut().insert(CORRELATION_ID_HEADER, value);
+    }
+    if let Some(client_id) = client_correlation_id {
+        response
+            .headers_mut()
+            .insert(CLIENT_CORRELATION_ID_HEADER, client_id);
+    }
+    response
+}
+
+/// Request extension marking a connection accepted on `gateway.unix_socket`.
+#[derive(Clone, Copy)]
+struct UnixSocketClient;
+
+/// Reject requests whose client IP fails `[gateway] ip_allowlist` /
+/// `ip_denylist` with `403`, before any route or auth logic runs. Unix
+/// socket clients have no IP and are not filtered; the socket's file
+/// permissions are their access boundary.
+async fn filter_source_ip(
+    State((filter, state)): State<(Arc<IpFilter>, AppState)>,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> Response {
+    if request.extensions().get::<UnixSocketClient>().is_some() {
+        return next.run(request).await;
+    }
+    let peer_addr = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| *addr);
+    let client_ip =
+        request_ip_from_request(peer_addr, request.headers(), state.trust_forwarded_headers);
+    if filter.permits(client_ip) {
+        return next.run(request).await;
+    }
+
+    let client = client_ip.map_or_else(|| "unknown".to_string(), |ip| ip.to_string());
+    let path = request.uri().path().to_string();
+    tracing::warn!("Gateway rejected request from {client} to {path}: source IP not allowed");
+    state
+        .observer
+        .record_event(&crate::observability::ObserverEvent::IpFilterRejected {
+            endpoint: path.clone(),
+        });
+    if let Some(audit) = state.audit.as_ref() {
+        let mut event = AuditEvent::new(AuditEventType::PolicyViolation)
+            .with_actor("gateway".to_string(), Some(client), None)
+            .with_action(
+                format!("{} {path}", request.method()),
+                "high".to_string(),
+                false,
+                false,
+            )
+            .with_result(false, None, 0, Some("source IP not allowed".to_string()));
+        if let Some(correlation_id) = request
+            .headers()
+            .get(CORRELATION_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+        {
+            event = event.with_correlation_id(correlation_id);
+        }
+        if let Err(e) = audit.log(&event) {
+            tracing::warn!("Failed to write gateway audit event: {e}");
+        }
+    }
+    (
+        StatusCode::FORBIDDEN,
+        Json(serde_json::json!({"error": "Source IP not allowed"})),
+    )
+        .into_response()
+}
+
+fn is_valid_correlation_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_CORRELATION_ID_LEN
+        && id
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+}
+
+/// Resolve on Ctrl+C or, on Unix, SIGTERM (what service managers send).
+pub(crate) async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::warn!("Failed to listen for Ctrl+C: {e}");
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to listen for SIGTERM: {e}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+/// Resolve once `rx` reports that shutdown was requested.
+async fn shutdown_requested(mut rx: tokio::sync::watch::Receiver<bool>) {
+    let _ = rx.wait_for(|requested| *requested).await;
+}
+
+/// The gateway's Unix socket file, removed when dropped so a clean shutdown
+/// leaves no stale socket behind.
+#[cfg(unix)]
+struct UnixSocketFile(std::path::PathBuf);
+
+#[cfg(unix)]
+impl Drop for UnixSocketFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Bind the gateway's Unix domain socket, replacing a stale socket file left
+/// by a previous run, and restrict it to `mode`. The socket is bound inside a
+/// private (0700) directory and moved to `path` once its mode is set, so it
+/// is never reachable with the looser permissions the umask would give it.
+#[cfg(unix)]
+fn bind_unix_socket(
+    path: &std::path::Path,
+    mode: u32,
+) -> Result<(tokio::net::UnixListener, UnixSocketFile)> {
+    use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            anyhow::bail!(
+                "gateway.unix_socket path exists and is not a socket: {}",
+                path.display()
+            );
+        }
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove stale socket: {}", path.display()))?;
+    }
+    let parent = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(std::path::Path::new("."));
+    std::fs::create_dir_all(parent)?;
+    let staging = tempfile::Builder::new()
+        .prefix(".gateway-sock")
+        .tempdir_in(parent)
+        .with_context(|| format!("Failed to create socket directory in {}", parent.display()))?;
+    let staged = staging.path().join("gateway.sock");
+    let listener = tokio::net::UnixListener::bind(&staged)
+        .with_context(|| format!("Failed to bind gateway socket: {}", path.display()))?;
+    std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(mode))?;
+    std::fs::rename(&staged, path)
+        .with_context(|| format!("Failed to move gateway socket to {}", path.display()))?;
+    Ok((listener, UnixSocketFile(path.to_path_buf())))
+}
+
+#[cfg(not(unix))]
+fn bind_unix_socket(
+    _path: &std::path::Path,
+    _mode: u32,
+) -> Result<(std::convert::Infallible, std::convert::Infallible)> {
+    anyhow::bail!("gateway.unix_socket is only supported on Unix platforms")
+}
+
+/// Count a rate-limited request for `/metrics`.
+pub(super) fn record_rate_limited(state: &AppState, endpoint: &str) {
+    state
+        .observer
+        .record_event(&crate::observability::ObserverEvent::RateLimitRejected {
+            endpoint: endpoint.to_string(),
+        });
+}
+
+/// Record a gateway authentication event in the audit log, if enabled.
+/// The event carries the request's correlation id so it joins the request trace.
+pub(super) fn audit_auth_event(
+    state: &AppState,
+    headers: &HeaderMap,
+    success: bool,
+    client: &str,
+    detail: &str,
+) {
+    let Some(audit) = state.audit.as_ref() else {
+        return;
+    };
+    let event_type = if success {
+        AuditEventType::AuthSuccess
+    } else {
+        AuditEventType::AuthFailure
+    };
+    let mut event = AuditEvent::new(event_type)
+        .with_actor("gateway".to_string(), Some(client.to_string()), None)
+        .with_result(success, None, 0, (!success).then(|| detail.to_string()));
+    if let Some(correlation_id) = headers
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+    {
+        event = event.with_correlation_id(correlation_id);
+    }
+    if let Err(e) = audit.log(&event) {
+        tracing::warn!("Failed to write gateway audit event: {e}");
+    }
+}
+
 /// POST /pair — exchange one-time code for bearer token
 #[axum::debug_handler]
 async fn handle_pair(
@@ -975,6 +1463,7 @@ async fn handle_pair(
         client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
     if !state.rate_limiter.allow_pair(&rate_key) {
         tracing::warn!("/pair rate limit exceeded");
+        record_rate_limited(&state, "/pair");
         let err = serde_json::json!({
             "error": "Too many pairing requests. Please retry later.",
             "retry_after": RATE_LIMIT_WINDOW_SECS,
@@ -990,6 +1479,7 @@ async fn handle_pair(
     match state.pairing.try_pair(code, &rate_key).await {
         Ok(Some(token)) => {
             tracing::info!("🔐 New client paired successfully");
+            audit_auth_event(&state, &headers, true, &rate_key, "paired");
             if let Err(err) = persist_pairing_tokens(state.config.clone(), &state.pairing).await {
                 tracing::error!("🔐 Pairing succeeded but token persistence failed: {err:#}");
                 let body = serde_json::json!({
@@ -1011,6 +1501,7 @@ async fn handle_pair(
         }
         Ok(None) => {
             tracing::warn!("🔐 Pairing attempt with invalid code");
+            audit_auth_event(&state, &headers, false, &rate_key, "invalid pairing code");
             let err = serde_json::json!({"error": "Invalid pairing code"});
             (StatusCode::FORBIDDEN, Json(err))
         }
@@ -1018,6 +1509,7 @@ async fn handle_pair(
             tracing::warn!(
                 "🔐 Pairing locked out — too many failed attempts ({lockout_secs}s remaining)"
             );
+            audit_auth_event(&state, &headers, false, &rate_key, "pairing locked out");
             let err = serde_json::json!({
                 "error": format!("Too many failed attempts. Try again in {lockout_secs}s."),
                 "retry_after": lockout_secs
@@ -1589,6 +2081,7 @@ async fn handle_webhook(
         client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
     if !state.rate_limiter.allow_webhook(&rate_key) {
         tracing::warn!("/webhook rate limit exceeded");
+        record_rate_limited(&state, "/webhook");
         let err = serde_json::json!({
             "error": "Too many webhook requests. Please retry later.",
             "retry_after": RATE_LIMIT_WINDOW_SECS,
@@ -2991,6 +3484,7 @@ mod tests {
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_metrics(State(state), test_connect_info(), HeaderMap::new())
@@ -3053,6 +3547,7 @@ mod tests {
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_metrics(State(state), test_connect_info(), HeaderMap::new())
@@ -3098,6 +3593,7 @@ mod tests {
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_metrics(State(state), test_public_connect_info(), HeaderMap::new())
@@ -3144,6 +3640,7 @@ mod tests {
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let unauthorized =
@@ -3163,6 +3660,327 @@ mod tests {
         assert_eq!(authorized.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn invalid_pairing_attempt_is_audited() {
+        let tmp = tempfile::tempdir().unwrap();
+        let audit_config = crate::config::AuditConfig {
+            enabled: true,
+            ..crate::config::AuditConfig::default()
+        };
+        let state = AppState {
+            config: Arc::new(Mutex::new(Config::default())),
+            provider: Arc::new(MockProvider::default()),
+            model: "test-model".into(),
+            temperature: 0.0,
+            mem: Arc::new(MockMemory),
+            auto_save: false,
+            webhook_secret_hash: None,
+            pairing: Arc::new(PairingGuard::new(true, &[])),
+            trust_forwarded_headers: false,
+            rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
+            idempotency_store: Arc::new(IdempotencyStore::new(Duration::from_secs(300), 1000)),
+            whatsapp: None,
+            whatsapp_app_secret: None,
+            linq: None,
+            linq_signing_secret: None,
+            bluebubbles: None,
+            bluebubbles_webhook_secret: None,
+            nextcloud_talk: None,
+            nextcloud_talk_webhook_secret: None,
+            wati: None,
+            wati_webhook_secret: None,
+            qq: None,
+            qq_webhook_enabled: false,
+            observer: Arc::new(crate::observability::NoopObserver),
+            tools_registry: Arc::new(Vec::new()),
+            tools_registry_exec: Arc::new(Vec::new()),
+            multimodal: crate::config::MultimodalConfig::default(),
+            max_tool_iterations: 10,
+            cost_tracker: None,
+            event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: Some(Arc::new(
+                AuditLogger::new(audit_config, tmp.path().to_path_buf()).unwrap(),
+            )),
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Pairing-Code", HeaderValue::from_static("not-a-code"));
+        let response = Box::pin(handle_pair(State(state), test_connect_info(), headers))
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let events = AuditLogger::load(&tmp.path().join("audit.log")).unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0].event_type, AuditEventType::AuthFailure));
+        assert_eq!(
+            events[0].result.as_ref().unwrap().error.as_deref(),
+            Some("invalid pairing code")
+        );
+    }
+
+    #[tokio::test]
+    async fn trace_request_replaces_client_correlation_ids() {
+        let mut app = Router::new()
+            .route(
+                "/",
+                get(|headers: HeaderMap| async move {
+                    headers
+                        .get(CORRELATION_ID_HEADER)
+                        .and_then(|value| value.to_str().ok())
+                        .unwrap_or_default()
+                        .to_string()
+                }),
+            )
+            .layer(axum::middleware::from_fn(trace_request));
+        let request = axum::extract::Request::builder()
+            .uri("/")
+            .header(CORRELATION_ID_HEADER, "client-chosen-id")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = tower::Service::call(&mut app, request).await.unwrap();
+        let server_id = response.headers()[CORRELATION_ID_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert_ne!(server_id, "client-chosen-id");
+        assert!(Uuid::parse_str(&server_id).is_ok());
+        assert_eq!(
+            response.headers()[CLIENT_CORRELATION_ID_HEADER],
+            "client-chosen-id"
+        );
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(body, server_id.as_bytes());
+    }
+
+    #[test]
+    fn correlation_ids_from_clients_are_validated() {
+        assert!(is_valid_correlation_id("req-123_abc"));
+        assert!(!is_valid_correlation_id(""));
+        assert!(!is_valid_correlation_id("has space"));
+        assert!(!is_valid_correlation_id(
+            &"a".repeat(MAX_CORRELATION_ID_LEN + 1)
+        ));
+    }
+
+    #[test]
+    fn event_filter_gates_audit_entries_on_the_events_role() {
+        let audit = serde_json::json!({"type": "audit", "event": {}});
+        let agent_start = serde_json::json!({"type": "agent_start"});
+        let mut config = Config::default();
+
+        // The default `viewer` role does not allow audit events.
+        let filter = EventFilter::new(&config, None);
+        assert!(!filter.permits(&audit));
+        assert!(filter.permits(&agent_start));
+
+        config.gateway.events_role = "admin".into();
+        assert!(EventFilter::new(&config, None).permits(&audit));
+
+        config.gateway.events_role = "auditor".into();
+        config.security.roles = vec![crate::config::SecurityRoleConfig {
+            name: "auditor".into(),
+            allowed_tools: vec!["audit_*".into()],
+            ..crate::config::SecurityRoleConfig::default()
+        }];
+        assert!(EventFilter::new(&config, None).permits(&audit));
+    }
+
+    #[test]
+    fn event_filter_forwards_only_subscribed_types() {
+        let mut config = Config::default();
+        config.gateway.events_role = "admin".into();
+        let filter = EventFilter::new(&config, Some("agent_start, audit"));
+        assert!(filter.permits(&serde_json::json!({"type": "agent_start"})));
+        assert!(filter.permits(&serde_json::json!({"type": "audit"})));
+        assert!(!filter.permits(&serde_json::json!({"type": "tool_call"})));
+        assert!(!filter.permits(&serde_json::json!({"data": 1})));
+    }
+
+    #[test]
+    fn ip_filter_denylist_wins_over_allowlist() {
+        let filter = IpFilter::from_config(&crate::config::GatewayConfig {
+            ip_allowlist: vec!["10.0.0.0/8".into()],
+            ip_denylist: vec!["10.0.0.66/32".into()],
+            ..crate::config::GatewayConfig::default()
+        })
+        .unwrap();
+        let ip = |raw: &str| Some(raw.parse::<IpAddr>().unwrap());
+
+        assert!(filter.permits(ip("10.1.2.3")));
+        assert!(!filter.permits(ip("10.0.0.66")));
+        assert!(!filter.permits(ip("192.168.1.1")));
+        assert!(!filter.permits(None));
+        assert!(IpFilter::default().permits(None));
+    }
+
+    #[test]
+    fn ip_filter_matches_ipv4_mapped_clients_as_ipv4() {
+        let filter = IpFilter::from_config(&crate::config::GatewayConfig {
+            ip_allowlist: vec!["10.0.0.0/8".into()],
+            ip_denylist: vec!["10.0.0.66/32".into()],
+            ..crate::config::GatewayConfig::default()
+        })
+        .unwrap();
+        let mapped = |raw: &str| SocketAddr::new(raw.parse::<IpAddr>().unwrap(), 40000);
+
+        let allowed =
+            request_ip_from_request(Some(mapped("::ffff:10.1.2.3")), &HeaderMap::new(), false);
+        assert_eq!(allowed, Some("10.1.2.3".parse().unwrap()));
+        assert!(filter.permits(allowed));
+        let denied =
+            request_ip_from_request(Some(mapped("::ffff:10.0.0.66")), &HeaderMap::new(), false);
+        assert!(!filter.permits(denied));
+        assert!(!filter.permits(Some("::ffff:10.0.0.66".parse().unwrap())));
+    }
+
+    #[tokio::test]
+    async fn ip_filter_skips_unix_socket_clients() {
+        let filter = Arc::new(
+            IpFilter::from_config(&crate::config::GatewayConfig {
+                ip_allowlist: vec!["10.0.0.0/8".into()],
+                ..crate::config::GatewayConfig::default()
+            })
+            .unwrap(),
+        );
+        let app = Router::new().route("/", get(|| async { "ok" })).layer(
+            axum::middleware::from_fn_with_state((filter, test_app_state()), filter_source_ip),
+        );
+        let loopback = ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 0)));
+        let request = |unix_socket: bool| {
+            let mut request = axum::extract::Request::builder()
+                .uri("/")
+                .body(Body::empty())
+                .unwrap();
+            request.extensions_mut().insert(loopback);
+            if unix_socket {
+                request.extensions_mut().insert(UnixSocketClient);
+            }
+            request
+        };
+
+        let tcp = tower::Service::call(&mut app.clone(), request(false))
+            .await
+            .unwrap();
+        assert_eq!(tcp.status(), StatusCode::FORBIDDEN);
+        let unix = tower::Service::call(&mut app.clone(), request(true))
+            .await
+            .unwrap();
+        assert_eq!(unix.status(), StatusCode::OK);
+    }
+
+    pub(super) fn test_app_state() -> AppState {
+        AppState {
+            config: Arc::new(Mutex::new(Config::default())),
+            provider: Arc::new(MockProvider::default()),
+            model: "test-model".into(),
+            temperature: 0.0,
+            mem: Arc::new(MockMemory),
+            auto_save: false,
+            webhook_secret_hash: None,
+            pairing: Arc::new(PairingGuard::new(false, &[])),
+            trust_forwarded_headers: false,
+            rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
+            idempotency_store: Arc::new(IdempotencyStore::new(Duration::from_secs(300), 1000)),
+            whatsapp: None,
+            whatsapp_app_secret: None,
+            linq: None,
+            linq_signing_secret: None,
+            bluebubbles: None,
+            bluebubbles_webhook_secret: None,
+            nextcloud_talk: None,
+            nextcloud_talk_webhook_secret: None,
+            wati: None,
+            wati_webhook_secret: None,
+            qq: None,
+            qq_webhook_enabled: false,
+            observer: Arc::new(crate::observability::NoopObserver),
+            tools_registry: Arc::new(Vec::new()),
+            tools_registry_exec: Arc::new(Vec::new()),
+            multimodal: crate::config::MultimodalConfig::default(),
+            max_tool_iterations: 10,
+            cost_tracker: None,
+            event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket_bind_replaces_stale_socket_and_sets_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("gateway.sock");
+        let (listener, socket_file) = bind_unix_socket(&path, 0o600).unwrap();
+        // The socket file outlives the listener, as after a crash.
+        std::mem::forget(socket_file);
+        drop(listener);
+        let (_listener, socket_file) = bind_unix_socket(&path, 0o660).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o660);
+        // Only the socket is left in the directory; the staging dir is gone.
+        assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 1);
+        drop(socket_file);
+        assert!(!path.exists());
+
+        let regular = tmp.path().join("not-a-socket");
+        std::fs::write(&regular, "keep").unwrap();
+        assert!(bind_unix_socket(&regular, 0o600).is_err());
+        assert_eq!(std::fs::read_to_string(&regular).unwrap(), "keep");
+    }
+
+    #[tokio::test]
+    async fn readyz_reports_missing_audit_logger() {
+        let mut config = Config::default();
+        config.security.audit.enabled = true;
+        let state = AppState {
+            config: Arc::new(Mutex::new(config)),
+            provider: Arc::new(MockProvider::default()),
+            model: "test-model".into(),
+            temperature: 0.0,
+            mem: Arc::new(MockMemory),
+            auto_save: false,
+            webhook_secret_hash: None,
+            pairing: Arc::new(PairingGuard::new(true, &[])),
+            trust_forwarded_headers: false,
+            rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
+            idempotency_store: Arc::new(IdempotencyStore::new(Duration::from_secs(300), 1000)),
+            whatsapp: None,
+            whatsapp_app_secret: None,
+            linq: None,
+            linq_signing_secret: None,
+            bluebubbles: None,
+            bluebubbles_webhook_secret: None,
+            nextcloud_talk: None,
+            nextcloud_talk_webhook_secret: None,
+            wati: None,
+            wati_webhook_secret: None,
+            qq: None,
+            qq_webhook_enabled: false,
+            observer: Arc::new(crate::observability::NoopObserver),
+            tools_registry: Arc::new(Vec::new()),
+            tools_registry_exec: Arc::new(Vec::new()),
+            multimodal: crate::config::MultimodalConfig::default(),
+            max_tool_iterations: 10,
+            cost_tracker: None,
+            event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
+        };
+
+        let live = handle_healthz().await.into_response();
+        assert_eq!(live.status(), StatusCode::OK);
+
+        let response = handle_readyz(State(state)).await.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["status"], "not_ready");
+        assert_eq!(body["checks"]["memory"]["ok"], true);
+        assert_eq!(body["checks"]["audit"]["ok"], false);
+    }
+
     #[test]
     fn gateway_rate_limiter_blocks_after_limit() {
         let limiter = GatewayRateLimiter::new(2, 2, 100);
@@ -3659,6 +4477,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -3733,6 +4552,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_webhook(
@@ -3788,6 +4608,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_webhook(
@@ -3844,6 +4665,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_webhook(
@@ -3909,6 +4731,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_node_control(
@@ -3967,6 +4790,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_node_control(
@@ -4031,6 +4855,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_node_control(
@@ -4089,6 +4914,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let headers = HeaderMap::new();
@@ -4177,6 +5003,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_webhook(
@@ -4235,6 +5062,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -4298,6 +5126,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -4405,6 +5234,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_wati_webhook(State(state), HeaderMap::new(), Bytes::from("{}"))
@@ -4457,6 +5287,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_wati_webhook(State(state), HeaderMap::new(), Bytes::from("{}"))
@@ -4511,6 +5342,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_wati_webhook(State(state), HeaderMap::new(), Bytes::from("{}"))
@@ -4566,6 +5398,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -4628,6 +5461,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -4688,6 +5522,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -4749,6 +5584,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -4810,6 +5646,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -4873,6 +5710,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -4928,6 +5766,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_github_webhook(
@@ -4984,6 +5823,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let body = r#"{
@@ -5051,6 +5891,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let body = r#"{
@@ -5123,6 +5964,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_nextcloud_talk_webhook(
@@ -5185,6 +6027,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -5240,6 +6083,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let response = handle_qq_webhook(
@@ -5294,6 +6138,7 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            audit: None,
         };
 
         let mut headers = HeaderMap::new();
@@ -155,6 +155,7 @@ pub async fn handle_v1_chat_completions(
         super::client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
     if !state.rate_limiter.allow_webhook(&rate_key) {
         tracing::warn!("/v1/chat/completions rate limit exceeded");
+        super::record_rate_limited(&state, "/v1/chat/completions");
         let err = serde_json::json!({
             "error": {
                 "message": "Rate limit exceeded. Please retry later.",
@@ -26,8 +26,8 @@
 //! have migrated to the native endpoint.
 
 use super::{
-    client_key_from_request, run_gateway_chat_with_tools, sanitize_gateway_response, AppState,
-    RATE_LIMIT_WINDOW_SECS,
+    client_key_from_request, record_rate_limited, run_gateway_chat_with_tools,
+    sanitize_gateway_response, AppState, RATE_LIMIT_WINDOW_SECS,
 };
 use crate::memory::MemoryCategory;
 use crate::providers;
@@ -83,6 +83,7 @@ pub async fn handle_api_chat(
         client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
     if !state.rate_limiter.allow_webhook(&rate_key) {
         tracing::warn!("/api/chat rate limit exceeded");
+        record_rate_limited(&state, "/api/chat");
         let err = serde_json::json!({
             "error": "Too many chat requests. Please retry later.",
             "retry_after": RATE_LIMIT_WINDOW_SECS,
@@ -370,6 +371,7 @@ pub async fn handle_v1_chat_completions_with_tools(
         client_key_from_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
     if !state.rate_limiter.allow_webhook(&rate_key) {
         tracing::warn!("/v1/chat/completions (compat) rate limit exceeded");
+        record_rate_limited(&state, "/v1/chat/completions");
         let err = serde_json::json!({
             "error": {
                 "message": "Rate limit exceeded. Please retry later.",
@@ -54,11 +54,28 @@ pub async fn handle_sse_events(
     let is_loopback_request =
         super::is_loopback_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
 
-    match evaluate_sse_auth(
+    let rejection = evaluate_sse_auth(
         state.pairing.require_pairing(),
         is_loopback_request,
         has_valid_pairing_token,
-    ) {
+    );
+    if let Some(rejection) = rejection {
+        let detail = match rejection {
+            SseAuthRejection::MissingPairingToken => {
+                "missing or invalid bearer token for /api/events"
+            }
+            SseAuthRejection::NonLocalWithoutAuthLayer => {
+                "non-local /api/events access without a paired token"
+            }
+        };
+        let client = super::client_key_from_request(
+            Some(peer_addr),
+            &headers,
+            state.trust_forwarded_headers,
+        );
+        super::audit_auth_event(&state, &headers, false, &client, detail);
+    }
+    match rejection {
         Some(SseAuthRejection::MissingPairingToken) => {
             return (
                 StatusCode::UNAUTHORIZED,
@@ -76,13 +93,16 @@ pub async fn handle_sse_events(
         None => {}
     }
 
+    // Audit entries are withheld unless `gateway.events_role` allows them.
+    let filter = super::EventFilter::new(&state.config.lock(), None);
     let rx = state.event_tx.subscribe();
     let stream = BroadcastStream::new(rx).filter_map(
-        |result: Result<
+        move |result: Result<
             serde_json::Value,
             tokio_stream::wrappers::errors::BroadcastStreamRecvError,
         >| {
             match result {
+                Ok(value) if !filter.permits(&value) => None,
                 Ok(value) => Some(Ok::<_, Infallible>(
                     Event::default().data(value.to_string()),
                 )),
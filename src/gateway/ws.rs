@@ -34,6 +34,8 @@ const MAX_WS_SESSION_ID_LEN: usize = 128;
 struct WsQueryParams {
     token: Option<String>,
     session_id: Option<String>,
+    /// `/ws/events` subscription: comma-separated event types
+    types: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
@@ -85,6 +87,9 @@ fn parse_ws_query_params(raw_query: Option<&str>) -> WsQueryParams {
             "session_id" if params.session_id.is_none() => {
                 params.session_id = normalize_ws_session_id(Some(value));
             }
+            "types" if params.types.is_none() => {
+                params.types = Some(value.to_string());
+            }
             _ => {}
         }
     }
@@ -373,42 +378,117 @@ pub async fn handle_ws_chat(
     ws: WebSocketUpgrade,
 ) -> impl IntoResponse {
     let query_params = parse_ws_query_params(query.as_deref());
-    let token =
-        extract_ws_bearer_token(&headers, query_params.token.as_deref()).unwrap_or_default();
+    if let Some(rejection) = authorize_ws(
+        &state,
+        peer_addr,
+        &headers,
+        query_params.token.as_deref(),
+        "/ws/chat",
+    ) {
+        return rejection;
+    }
+
+    let session_id = query_params
+        .session_id
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    ws.on_upgrade(move |socket| handle_socket(socket, state, session_id))
+        .into_response()
+}
+
+/// Check pairing/loopback access for a WebSocket route. Returns the
+/// rejection response when the client may not connect, after auditing the
+/// failure with the client's IP.
+fn authorize_ws(
+    state: &AppState,
+    peer_addr: SocketAddr,
+    headers: &HeaderMap,
+    query_token: Option<&str>,
+    route: &str,
+) -> Option<axum::response::Response> {
+    let token = extract_ws_bearer_token(headers, query_token).unwrap_or_default();
     let has_valid_pairing_token = !token.is_empty() && state.pairing.is_authenticated(&token);
     let is_loopback_request =
-        super::is_loopback_request(Some(peer_addr), &headers, state.trust_forwarded_headers);
+        super::is_loopback_request(Some(peer_addr), headers, state.trust_forwarded_headers);
 
-    match evaluate_ws_auth(
+    let (message, detail) = match evaluate_ws_auth(
         state.pairing.require_pairing(),
         is_loopback_request,
         has_valid_pairing_token,
+    )? {
+        WsAuthRejection::MissingPairingToken => (
+            "Unauthorized — provide Authorization: Bearer <token>, Sec-WebSocket-Protocol: bearer.<token>, or ?token=<token>".to_string(),
+            format!("missing or invalid bearer token for {route}"),
+        ),
+        WsAuthRejection::NonLocalWithoutAuthLayer => (
+            format!("Unauthorized — enable gateway pairing or provide a valid paired bearer token for non-local {route} access"),
+            format!("non-local {route} access without a paired token"),
+        ),
+    };
+    let client =
+        super::client_key_from_request(Some(peer_addr), headers, state.trust_forwarded_headers);
+    super::audit_auth_event(state, headers, false, &client, &detail);
+    Some((axum::http::StatusCode::UNAUTHORIZED, message).into_response())
+}
+
+/// GET /ws/events — live stream of gateway events, including audit entries.
+///
+/// Sends the same JSON payloads as `/api/events`, one per text frame.
+/// `?types=agent_start,audit` limits the stream to those event types. Audit
+/// entries arrive as `{"type":"audit","event":{...}}`, and only when
+/// `gateway.events_role` allows them. Clients that fall behind skip events
+/// rather than stall the stream.
+pub async fn handle_ws_events(
+    State(state): State<AppState>,
+    ConnectInfo(peer_addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    RawQuery(query): RawQuery,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    let params = parse_ws_query_params(query.as_deref());
+    if let Some(rejection) = authorize_ws(
+        &state,
+        peer_addr,
+        &headers,
+        params.token.as_deref(),
+        "/ws/events",
     ) {
-        Some(WsAuthRejection::MissingPairingToken) => {
-            return (
-                axum::http::StatusCode::UNAUTHORIZED,
-                "Unauthorized — provide Authorization: Bearer <token>, Sec-WebSocket-Protocol: bearer.<token>, or ?token=<token>",
-            )
-                .into_response();
-        }
-        Some(WsAuthRejection::NonLocalWithoutAuthLayer) => {
-            return (
-                axum::http::StatusCode::UNAUTHORIZED,
-                "Unauthorized — enable gateway pairing or provide a valid paired bearer token for non-local /ws/chat access",
-            )
-                .into_response();
-        }
-        None => {}
+        return rejection;
     }
 
-    let session_id = query_params
-        .session_id
-        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
-
-    ws.on_upgrade(move |socket| handle_socket(socket, state, session_id))
+    let filter = super::EventFilter::new(&state.config.lock(), params.types.as_deref());
+    let rx = state.event_tx.subscribe();
+    ws.on_upgrade(move |socket| stream_events(socket, rx, filter))
         .into_response()
 }
 
+async fn stream_events(
+    mut socket: WebSocket,
+    mut rx: tokio::sync::broadcast::Receiver<serde_json::Value>,
+    filter: super::EventFilter,
+) {
+    use tokio::sync::broadcast::error::RecvError;
+
+    loop {
+        tokio::select! {
+            event = rx.recv() => match event {
+                Ok(event) if !filter.permits(&event) => {}
+                Ok(event) => {
+                    if socket.send(Message::Text(event.to_string().into())).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => break,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
 async fn handle_socket(mut socket: WebSocket, state: AppState, session_id: String) {
     let ws_session_id = format!("ws_{}", Uuid::new_v4());
 
@@ -661,6 +741,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rejected_ws_auth_is_audited_with_client_ip() {
+        use crate::security::audit::{AuditEventType, AuditLogger};
+        use std::sync::Arc;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let audit = AuditLogger::new(
+            crate::config::AuditConfig {
+                enabled: true,
+                ..crate::config::AuditConfig::default()
+            },
+            tmp.path().to_path_buf(),
+        )
+        .unwrap();
+        let state = AppState {
+            pairing: Arc::new(crate::security::pairing::PairingGuard::new(true, &[])),
+            audit: Some(Arc::new(audit)),
+            ..super::super::tests::test_app_state()
+        };
+        let peer: SocketAddr = "203.0.113.7:40000".parse().unwrap();
+
+        let rejection = authorize_ws(&state, peer, &HeaderMap::new(), Some("bogus"), "/ws/events");
+        assert_eq!(
+            rejection.unwrap().status(),
+            axum::http::StatusCode::UNAUTHORIZED
+        );
+
+        let events = AuditLogger::load(&tmp.path().join("audit.log")).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, AuditEventType::AuthFailure);
+        let actor = events[0].actor.as_ref().unwrap();
+        assert_eq!(actor.user_id.as_deref(), Some("203.0.113.7"));
+        assert_eq!(
+            events[0].result.as_ref().unwrap().error.as_deref(),
+            Some("missing or invalid bearer token for /ws/events")
+        );
+    }
+
     #[test]
     fn extract_query_token_reads_token_param() {
         assert_eq!(
@@ -672,9 +790,12 @@ mod tests {
 
     #[test]
     fn parse_ws_query_params_reads_token_and_session_id() {
-        let parsed = parse_ws_query_params(Some("foo=1&session_id=sess_123&token=query-token"));
+        let parsed = parse_ws_query_params(Some(
+            "foo=1&session_id=sess_123&token=query-token&types=audit,agent_start",
+        ));
         assert_eq!(parsed.token.as_deref(), Some("query-token"));
         assert_eq!(parsed.session_id.as_deref(), Some("sess_123"));
+        assert_eq!(parsed.types.as_deref(), Some("audit,agent_start"));
     }
 
     #[test]
@@ -380,6 +380,63 @@ pub enum MemoryCommands {
     },
 }
 
+/// Audit log subcommands
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AuditCommands {
+    /// Query audit events with optional filters
+    Query {
+        /// Match the actor's user id or username
+        #[arg(long)]
+        user: Option<String>,
+        /// Only events from the last N hours
+        #[arg(long)]
+        hours: Option<u32>,
+        /// Filter by command exit code
+        #[arg(long)]
+        exit_code: Option<i32>,
+        /// Substring of the executed command
+        #[arg(long)]
+        command: Option<String>,
+        /// Filter by correlation id
+        #[arg(long)]
+        correlation_id: Option<String>,
+        /// Filter by event type (e.g. `policy_violation`, `auth_failure`)
+        #[arg(long)]
+        event_type: Option<String>,
+        /// Only events at or above this severity (info, notice, warning, critical)
+        #[arg(long)]
+        severity: Option<String>,
+        /// Filter by the channel the event came from (e.g. `telegram`, `mcp`)
+        #[arg(long)]
+        channel: Option<String>,
+        /// Maximum number of events to display
+        #[arg(long, default_value = "50")]
+        limit: usize,
+        /// Number of events to skip (for pagination)
+        #[arg(long, default_value = "0")]
+        offset: usize,
+        /// Print events as JSON lines
+        #[arg(long)]
+        json: bool,
+    },
+    /// Verify the hash chain and signatures of the stored audit history
+    Verify,
+    /// Print a Markdown summary of recent audit activity
+    Report {
+        /// Length of the reporting period in days
+        #[arg(long, default_value = "7")]
+        days: u32,
+    },
+    /// Reconstruct the timeline of a correlated command execution
+    Replay {
+        /// Correlation id shared by the audit events and anomaly alerts
+        correlation_id: String,
+        /// Print the timeline as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 /// Integration subcommands
 #[derive(Subcommand, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum IntegrationCommands {
@@ -100,8 +100,8 @@ use config::Config;
 
 // Re-export so binary modules can use crate::<CommandEnum> while keeping a single source of truth.
 pub use zeroclaw::{
-    ChannelCommands, CronCommands, HardwareCommands, IntegrationCommands, MigrateCommands,
-    PeripheralCommands, ServiceCommands, SkillCommands,
+    AuditCommands, ChannelCommands, CronCommands, HardwareCommands, IntegrationCommands,
+    MigrateCommands, PeripheralCommands, ServiceCommands, SkillCommands,
 };
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
@@ -305,6 +305,20 @@ Examples:
         host: Option<String>,
     },
 
+    /// Serve guarded tools to MCP clients over stdio
+    #[command(long_about = "\
+Run ZeroClaw as a Model Context Protocol (MCP) server on stdio.
+
+Exposes the shell, file, and search tools (plus http_request when
+enabled) to MCP clients. Every call is checked by the configured
+security policy and recorded in the audit log. Logs go to stderr so
+stdout carries only JSON-RPC.
+
+Examples:
+  zeroclaw mcp-serve
+  RUST_LOG=warn zeroclaw mcp-serve")]
+    McpServe,
+
     /// Manage OS service lifecycle (launchd/systemd user service)
     Service {
         /// Init system to use: auto (detect), systemd, or openrc
@@ -536,6 +550,24 @@ Examples:
         memory_command: MemoryCommands,
     },
 
+    /// Inspect and verify the security audit log
+    #[command(long_about = "\
+Inspect the security audit log.
+
+Query events, verify tamper evidence, summarize recent activity, and
+reconstruct what a single command execution did.
+
+Examples:
+  zeroclaw audit query --user alice --hours 24
+  zeroclaw audit query --exit-code 1 --limit 20 --json
+  zeroclaw audit verify
+  zeroclaw audit report --days 7
+  zeroclaw audit replay <correlation-id>")]
+    Audit {
+        #[command(subcommand)]
+        audit_command: AuditCommands,
+    },
+
     /// Manage configuration
     #[command(long_about = "\
 Manage ZeroClaw configuration.
@@ -819,12 +851,21 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
-    // Initialize logging - respects RUST_LOG env var, defaults to INFO
+    // Initialize logging - respects RUST_LOG env var, defaults to INFO.
+    // MCP stdio mode reserves stdout for JSON-RPC, so its logs go to stderr.
+    let log_to_stderr = matches!(cli.command, Commands::McpServe);
     let subscriber = fmt::Subscriber::builder()
         .with_timer(tracing_subscriber::fmt::time::ChronoLocal::rfc_3339())
         .with_env_filter(
             EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
         )
+        .with_writer(move || -> Box<dyn std::io::Write> {
+            if log_to_stderr {
+                Box::new(std::io::stderr())
+            } else {
+                Box::new(std::io::stdout())
+            }
+        })
         .finish();
 
     tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
@@ -1020,6 +1061,8 @@ async fn main() -> Result<()> {
             daemon::run(config, host, port).await
         }
 
+        Commands::McpServe => Box::pin(tools::mcp_server::run(config)).await,
+
         Commands::Status => {
             println!("🦀 ZeroClaw Status");
             println!();
@@ -1248,6 +1291,10 @@ async fn main() -> Result<()> {
             memory::cli::handle_command(memory_command, &config).await
         }
 
+        Commands::Audit { audit_command } => {
+            security::audit_cli::handle_command(audit_command, &config)
+        }
+
         Commands::Auth { auth_command } => handle_auth_command(auth_command, &config).await,
 
         Commands::Hardware { hardware_command } => {
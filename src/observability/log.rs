@@ -56,6 +56,12 @@ impl Observer for LogObserver {
                     "webhook.auth.failure"
                 );
             }
+            ObserverEvent::RateLimitRejected { endpoint } => {
+                info!(endpoint = %endpoint, "rate_limit.rejected");
+            }
+            ObserverEvent::IpFilterRejected { endpoint } => {
+                info!(endpoint = %endpoint, "ip_filter.rejected");
+            }
             ObserverEvent::HeartbeatTick => {
                 info!("heartbeat.tick");
             }
@@ -22,6 +22,8 @@ pub struct OtelObserver {
     tool_duration: Histogram<f64>,
     channel_messages: Counter<u64>,
     webhook_auth_failures: Counter<u64>,
+    rate_limit_rejections: Counter<u64>,
+    ip_filter_rejections: Counter<u64>,
     heartbeat_ticks: Counter<u64>,
     errors: Counter<u64>,
     request_latency: Histogram<f64>,
@@ -127,6 +129,16 @@ impl OtelObserver {
             .with_description("Total webhook authentication failures")
             .build();
 
+        let rate_limit_rejections = meter
+            .u64_counter("zeroclaw.rate_limit.rejections")
+            .with_description("Total requests rejected by gateway rate limits")
+            .build();
+
+        let ip_filter_rejections = meter
+            .u64_counter("zeroclaw.ip_filter.rejections")
+            .with_description("Total requests rejected by the gateway source-IP filter")
+            .build();
+
         let heartbeat_ticks = meter
             .u64_counter("zeroclaw.heartbeat.ticks")
             .with_description("Total heartbeat ticks")
@@ -169,6 +181,8 @@ impl OtelObserver {
             tool_duration,
             channel_messages,
             webhook_auth_failures,
+            rate_limit_rejections,
+            ip_filter_rejections,
             heartbeat_ticks,
             errors,
             request_latency,
@@ -337,6 +351,14 @@ impl Observer for OtelObserver {
                     ],
                 );
             }
+            ObserverEvent::RateLimitRejected { endpoint } => {
+                self.rate_limit_rejections
+                    .add(1, &[KeyValue::new("endpoint", endpoint.clone())]);
+            }
+            ObserverEvent::IpFilterRejected { endpoint } => {
+                self.ip_filter_rejections
+                    .add(1, &[KeyValue::new("endpoint", endpoint.clone())]);
+            }
             ObserverEvent::HeartbeatTick => {
                 self.heartbeat_ticks.add(1, &[]);
             }
@@ -16,6 +16,8 @@ pub struct PrometheusObserver {
     tool_calls: IntCounterVec,
     channel_messages: IntCounterVec,
     webhook_auth_failures: IntCounterVec,
+    rate_limit_rejections: IntCounterVec,
+    ip_filter_rejections: IntCounterVec,
     heartbeat_ticks: prometheus::IntCounter,
     errors: IntCounterVec,
 
@@ -82,6 +84,24 @@ impl PrometheusObserver {
         )
         .context("failed to create zeroclaw_webhook_auth_failures_total counter")?;
 
+        let rate_limit_rejections = IntCounterVec::new(
+            prometheus::Opts::new(
+                "zeroclaw_rate_limit_rejections_total",
+                "Total requests rejected by gateway rate limits",
+            ),
+            &["endpoint"],
+        )
+        .context("failed to create zeroclaw_rate_limit_rejections_total counter")?;
+
+        let ip_filter_rejections = IntCounterVec::new(
+            prometheus::Opts::new(
+                "zeroclaw_ip_filter_rejections_total",
+                "Total requests rejected by the gateway source-IP filter",
+            ),
+            &["endpoint"],
+        )
+        .context("failed to create zeroclaw_ip_filter_rejections_total counter")?;
+
         let heartbeat_ticks =
             prometheus::IntCounter::new("zeroclaw_heartbeat_ticks_total", "Total heartbeat ticks")
                 .context("failed to create zeroclaw_heartbeat_ticks_total counter")?;
@@ -161,6 +181,12 @@ impl PrometheusObserver {
         registry
             .register(Box::new(webhook_auth_failures.clone()))
             .context("failed to register zeroclaw_webhook_auth_failures_total counter")?;
+        registry
+            .register(Box::new(rate_limit_rejections.clone()))
+            .context("failed to register zeroclaw_rate_limit_rejections_total counter")?;
+        registry
+            .register(Box::new(ip_filter_rejections.clone()))
+            .context("failed to register zeroclaw_ip_filter_rejections_total counter")?;
         registry
             .register(Box::new(heartbeat_ticks.clone()))
             .context("failed to register zeroclaw_heartbeat_ticks_total counter")?;
@@ -195,6 +221,8 @@ impl PrometheusObserver {
             tool_calls,
             channel_messages,
             webhook_auth_failures,
+            rate_limit_rejections,
+            ip_filter_rejections,
             heartbeat_ticks,
             errors,
             agent_duration,
@@ -292,6 +320,16 @@ impl Observer for PrometheusObserver {
                     .with_label_values(&[channel, signature, bearer])
                     .inc();
             }
+            ObserverEvent::RateLimitRejected { endpoint } => {
+                self.rate_limit_rejections
+                    .with_label_values(&[endpoint])
+                    .inc();
+            }
+            ObserverEvent::IpFilterRejected { endpoint } => {
+                self.ip_filter_rejections
+                    .with_label_values(&[endpoint])
+                    .inc();
+            }
             ObserverEvent::HeartbeatTick => {
                 self.heartbeat_ticks.inc();
             }
@@ -422,11 +460,15 @@ mod tests {
             signature: "invalid".into(),
             bearer: "missing".into(),
         });
+        obs.record_event(&ObserverEvent::RateLimitRejected {
+            endpoint: "/webhook".into(),
+        });
         obs.record_event(&ObserverEvent::HeartbeatTick);
         obs.record_metric(&ObserverMetric::RequestLatency(Duration::from_millis(250)));
 
         let output = obs.encode();
         assert!(output.contains("zeroclaw_agent_starts_total"));
+        assert!(output.contains("zeroclaw_rate_limit_rejections_total"));
         assert!(output.contains("zeroclaw_tool_calls_total"));
         assert!(output.contains("zeroclaw_webhook_auth_failures_total"));
         assert!(output.contains("zeroclaw_heartbeat_ticks_total"));
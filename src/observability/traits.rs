@@ -65,6 +65,16 @@ pub enum ObserverEvent {
         /// Bearer auth status (`"missing"`, `"invalid"`, `"valid"`).
         bearer: String,
     },
+    /// A request was rejected by a gateway rate limiter.
+    RateLimitRejected {
+        /// Route that rejected the request (e.g., `"/webhook"`, `"/pair"`).
+        endpoint: String,
+    },
+    /// A request was rejected by the gateway source-IP filter.
+    IpFilterRejected {
+        /// Route the rejected request targeted.
+        endpoint: String,
+    },
     /// Periodic heartbeat tick from the runtime keep-alive loop.
     HeartbeatTick,
     /// An error occurred in a named component.
@@ -0,0 +1,204 @@
+//! Webhook alerts for high-severity audit events.
+//!
+//! When `[security.audit.alerts]` is enabled, [`AuditLogger`] appends every
+//! event at or above `min_severity` to an alert spool next to the audit log.
+//! [`AuditAlerter`] polls the spool and POSTs each event to every configured
+//! webhook, signing the body with HMAC-SHA256 when a secret is set. Alerts
+//! that still fail after `max_retries` retries are appended to a dead-letter
+//! file so they are never silently lost.
+//!
+//! [`AuditLogger`]: super::audit::AuditLogger
+
+use super::audit::alert_spool_path;
+use super::audit_spool::{send_with_retry, SpoolClaim};
+use crate::config::{AuditAlertsConfig, AuditConfig};
+use anyhow::Result;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Header carrying `sha256=<hex>` over the request body.
+pub const SIGNATURE_HEADER: &str = "X-Zeroclaw-Signature";
+
+const ALERT_TIMEOUT_SECS: u64 = 15;
+const ALERT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Delivers spooled audit alerts to webhooks.
+pub struct AuditAlerter {
+    config: AuditAlertsConfig,
+    spool: SpoolClaim,
+    dead_letter_path: PathBuf,
+    client: reqwest::Client,
+}
+
+impl AuditAlerter {
+    /// Build an alerter for the audit log configured under `zeroclaw_dir`.
+    pub fn new(audit_config: &AuditConfig, zeroclaw_dir: &Path) -> Self {
+        let spool_path = alert_spool_path(&zeroclaw_dir.join(&audit_config.log_path));
+        Self {
+            config: audit_config.alerts.clone(),
+            dead_letter_path: PathBuf::from(format!("{}.dead", spool_path.display())),
+            spool: SpoolClaim::new(spool_path),
+            client: crate::config::build_runtime_proxy_client_with_timeouts(
+                "security.audit_alerts",
+                ALERT_TIMEOUT_SECS,
+                ALERT_CONNECT_TIMEOUT_SECS,
+            ),
+        }
+    }
+
+    /// Deliver pending alerts every `poll_interval_secs` until cancelled.
+    pub async fn run(self) -> Result<()> {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.poll_interval_secs.max(1)));
+        loop {
+            interval.tick().await;
+            match self.flush().await {
+                Ok(0) => {}
+                Ok(sent) => tracing::debug!("sent {sent} audit alerts"),
+                Err(e) => tracing::warn!("audit alert delivery failed: {e:#}"),
+            }
+        }
+    }
+
+    /// Send every spooled alert to every webhook. Returns the number of
+    /// successful deliveries; failed ones are moved to the dead-letter file.
+    pub async fn flush(&self) -> Result<usize> {
+        let Some(content) = self.spool.claim()? else {
+            return Ok(0);
+        };
+        let mut delivered = 0;
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            let body = match serde_json::from_str::<serde_json::Value>(line) {
+                Ok(event) => serde_json::to_vec(&serde_json::json!({ "event": event }))?,
+                Err(e) => {
+                    self.dead_letter(None, line, &format!("malformed event: {e}"))?;
+                    continue;
+                }
+            };
+            for url in &self.config.webhook_urls {
+                match self.send(url.trim(), &body).await {
+                    Ok(()) => delivered += 1,
+                    Err(e) => self.dead_letter(Some(url), line, &format!("{e:#}"))?,
+                }
+            }
+        }
+
+        self.spool.complete()?;
+        Ok(delivered)
+    }
+
+    async fn send(&self, url: &str, body: &[u8]) -> Result<()> {
+        let signature = self.config.hmac_secret.as_deref().map(|secret| {
+            let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+                .expect("HMAC accepts keys of any length");
+            mac.update(body);
+            format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+        });
+
+        send_with_retry("webhook", self.config.max_retries, || {
+            let request = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.to_vec());
+            match signature.as_deref() {
+                Some(signature) => request.header(SIGNATURE_HEADER, signature),
+                None => request,
+            }
+        })
+        .await
+    }
+
+    fn dead_letter(&self, url: Option<&str>, line: &str, error: &str) -> Result<()> {
+        let entry = serde_json::json!({
+            "failed_at": chrono::Utc::now().to_rfc3339(),
+            "url": url,
+            "error": error,
+            "event": serde_json::from_str::<serde_json::Value>(line)
+                .unwrap_or_else(|_| serde_json::Value::String(line.to_string())),
+        });
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.dead_letter_path)?;
+        writeln!(file, "{entry}")?;
+        file.sync_all()?;
+        tracing::warn!(
+            "audit alert dead-lettered to {}: {error}",
+            self.dead_letter_path.display()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::audit::{AuditEvent, AuditEventType, AuditLogger};
+    use tempfile::TempDir;
+    use wiremock::matchers::{header_exists, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn alerts_config(url: String) -> AuditConfig {
+        AuditConfig {
+            enabled: true,
+            alerts: AuditAlertsConfig {
+                enabled: true,
+                webhook_urls: vec![url],
+                hmac_secret: Some("alert-secret".into()),
+                max_retries: 0,
+                ..AuditAlertsConfig::default()
+            },
+            ..AuditConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn only_events_at_threshold_are_sent_signed() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header_exists(SIGNATURE_HEADER))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tmp = TempDir::new()?;
+        let config = alerts_config(server.uri());
+        let logger = AuditLogger::new(config.clone(), tmp.path().to_path_buf())?;
+        logger.log(&AuditEvent::new(AuditEventType::AuthSuccess))?;
+        logger.log(&AuditEvent::new(AuditEventType::SecurityEvent))?;
+
+        let alerter = AuditAlerter::new(&config, tmp.path());
+        assert_eq!(alerter.flush().await?, 1);
+        assert_eq!(alerter.flush().await?, 0);
+        assert!(!alerter.dead_letter_path.exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn undeliverable_alerts_are_dead_lettered() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let tmp = TempDir::new()?;
+        let config = alerts_config(server.uri());
+        let logger = AuditLogger::new(config.clone(), tmp.path().to_path_buf())?;
+        logger.log(&AuditEvent::new(AuditEventType::SecurityEvent))?;
+
+        let alerter = AuditAlerter::new(&config, tmp.path());
+        assert_eq!(alerter.flush().await?, 0);
+        let dead = std::fs::read_to_string(&alerter.dead_letter_path)?;
+        assert_eq!(dead.lines().count(), 1);
+        assert!(dead.contains("webhook returned 500"));
+        assert!(!alerter.spool.inflight_path.exists());
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use super::audit::{read_public_key, AuditEvent, AuditLogger, AuditQuery};
+use super::audit_report;
+use super::session_replay::SessionReplay;
+use super::syscall_anomaly::resolve_log_path;
+use crate::config::Config;
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use console::style;
+
+/// Handle `zeroclaw audit <subcommand>` CLI commands.
+pub fn handle_command(command: crate::AuditCommands, config: &Config) -> Result<()> {
+    let zeroclaw_dir = config.zeroclaw_dir();
+    let logger = AuditLogger::open_read_only(config.security.audit.clone(), &zeroclaw_dir)?;
+
+    match command {
+        crate::AuditCommands::Query {
+            user,
+            hours,
+            exit_code,
+            command,
+            correlation_id,
+            event_type,
+            severity,
+            channel,
+            limit,
+            offset,
+            json,
+        } => {
+            let events = logger.query(&AuditQuery {
+                since: hours.map(|hours| Utc::now() - Duration::hours(i64::from(hours))),
+                user,
+                exit_code,
+                command_contains: command,
+                correlation_id,
+                event_type: event_type.as_deref().map(str::parse).transpose()?,
+                min_severity: severity.as_deref().map(str::parse).transpose()?,
+                channel,
+                offset,
+                limit: Some(limit),
+                ..AuditQuery::default()
+            })?;
+            if json {
+                for event in &events {
+                    println!("{}", serde_json::to_string(event)?);
+                }
+            } else if events.is_empty() {
+                println!("No matching audit events.");
+            } else {
+                for event in &events {
+                    println!("{}", format_event(event));
+                }
+            }
+            Ok(())
+        }
+        crate::AuditCommands::Verify => {
+            let audit = &config.security.audit;
+            let public_key = if audit.sign_events {
+                read_public_key(&zeroclaw_dir)?
+            } else {
+                None
+            };
+            if !audit.hash_chain && public_key.is_none() {
+                println!(
+                    "Nothing to verify: enable [security.audit] hash_chain or sign_events first."
+                );
+                return Ok(());
+            }
+            let count = logger.verify_history(public_key.as_deref())?;
+            if count == 0 {
+                println!("No audit events to verify.");
+                return Ok(());
+            }
+            if audit.hash_chain {
+                println!("{} hash chain intact ({count} events)", style("✓").green());
+            }
+            if public_key.is_some() {
+                println!("{} signatures valid ({count} events)", style("✓").green());
+            }
+            if audit.archive_rotated {
+                let count = logger.verify_archives()?;
+                println!("{} archives match manifest ({count})", style("✓").green());
+            }
+            Ok(())
+        }
+        crate::AuditCommands::Report { days } => {
+            let report = audit_report::report(&logger, Duration::days(i64::from(days)))?;
+            print!("{}", report.to_markdown());
+            Ok(())
+        }
+        crate::AuditCommands::Replay {
+            correlation_id,
+            json,
+        } => {
+            let anomaly_log =
+                resolve_log_path(&zeroclaw_dir, &config.security.syscall_anomaly.log_path);
+            let replay = SessionReplay::build(&logger, Some(&anomaly_log), &correlation_id)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&replay)?);
+            } else {
+                print!("{}", replay.render_text());
+            }
+            Ok(())
+        }
+    }
+}
+
+fn format_event(event: &AuditEvent) -> String {
+    let actor = event
+        .actor
+        .as_ref()
+        .map(|actor| {
+            let user = actor
+                .username
+                .as_deref()
+                .or(actor.user_id.as_deref())
+                .unwrap_or("-");
+            format!("{}/{user}", actor.channel)
+        })
+        .unwrap_or_else(|| "-".to_string());
+    let command = event
+        .action
+        .as_ref()
+        .and_then(|action| action.command.as_deref())
+        .unwrap_or("");
+    let outcome = match event.result.as_ref() {
+        Some(result) if result.success => style("ok").green().to_string(),
+        Some(result) => style(format!(
+            "failed{}",
+            result
+                .exit_code
+                .map(|code| format!(" ({code})"))
+                .unwrap_or_default()
+        ))
+        .red()
+        .to_string(),
+        None => String::new(),
+    };
+    format!(
+        "{}  {:<17}  {:<20}  {outcome}  {command}",
+        event.timestamp.format("%Y-%m-%d %H:%M:%S"),
+        event.event_type.as_str(),
+        actor
+    )
+}
@@ -0,0 +1,198 @@
+//! SIEM renderings of audit events.
+//!
+//! [`to_cef`] and [`to_leef`] render an [`AuditEvent`] as ArcSight CEF and
+//! QRadar LEEF 1.0 lines with a fixed field mapping: the event type is the
+//! signature / event id, and [`AuditSeverity`] maps onto the 0–10 scale.
+
+use super::audit::{AuditEvent, AuditEventType, AuditSeverity};
+
+const VENDOR: &str = "ZeroClaw";
+const PRODUCT: &str = "zeroclaw";
+
+/// Render `event` as a CEF:0 line.
+pub fn to_cef(event: &AuditEvent) -> String {
+    let mut extension = vec![
+        ("rt", event.timestamp.timestamp_millis().to_string()),
+        ("externalId", event.event_id.clone()),
+    ];
+    for (field, value) in common_fields(event) {
+        let key = cef_key(field);
+        // Custom string/number slots are named by a companion label key.
+        let label_key = match key {
+            "cs1" => Some("cs1Label"),
+            "cs2" => Some("cs2Label"),
+            "cn1" => Some("cn1Label"),
+            _ => None,
+        };
+        if let Some(label_key) = label_key {
+            extension.push((label_key, field.to_string()));
+        }
+        extension.push((key, value));
+    }
+    let extension = extension
+        .into_iter()
+        .map(|(key, value)| format!("{key}={}", escape_cef_value(&value)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "CEF:0|{VENDOR}|{PRODUCT}|{}|{}|{}|{}|{extension}",
+        escape_cef_header(env!("CARGO_PKG_VERSION")),
+        event.event_type.as_str(),
+        event_name(event.event_type),
+        severity_score(event.severity()),
+    )
+}
+
+/// Render `event` as a LEEF:1.0 line (tab-delimited attributes).
+pub fn to_leef(event: &AuditEvent) -> String {
+    let mut attributes = vec![
+        ("devTime", event.timestamp.timestamp_millis().to_string()),
+        ("sev", severity_score(event.severity()).to_string()),
+        ("cat", event.event_type.as_str().to_string()),
+        ("eventId", event.event_id.clone()),
+    ];
+    attributes.extend(
+        common_fields(event)
+            .into_iter()
+            .map(|(key, value)| (leef_key(key), value)),
+    );
+    let attributes = attributes
+        .into_iter()
+        .map(|(key, value)| format!("{key}={}", escape_leef_value(&value)))
+        .collect::<Vec<_>>()
+        .join("\t");
+
+    format!(
+        "LEEF:1.0|{VENDOR}|{PRODUCT}|{}|{}|{attributes}",
+        env!("CARGO_PKG_VERSION"),
+        event.event_type.as_str(),
+    )
+}
+
+/// Fields shared by both formats, keyed by a format-neutral name.
+fn common_fields(event: &AuditEvent) -> Vec<(&'static str, String)> {
+    let mut fields = Vec::new();
+    if let Some(actor) = &event.actor {
+        fields.push(("channel", actor.channel.clone()));
+        if let Some(user) = actor.username.as_ref().or(actor.user_id.as_ref()) {
+            fields.push(("user", user.clone()));
+        }
+    }
+    if let Some(action) = &event.action {
+        if let Some(command) = &action.command {
+            fields.push(("command", command.clone()));
+        }
+        let decision = if action.allowed { "allowed" } else { "blocked" };
+        fields.push(("decision", decision.to_string()));
+    }
+    if let Some(result) = &event.result {
+        let outcome = if result.success { "success" } else { "failure" };
+        fields.push(("outcome", outcome.to_string()));
+        if let Some(code) = result.exit_code {
+            fields.push(("exit_code", code.to_string()));
+        }
+        if let Some(error) = &result.error {
+            fields.push(("reason", error.clone()));
+        }
+    }
+    if let Some(correlation_id) = &event.correlation_id {
+        fields.push(("correlation_id", correlation_id.clone()));
+    }
+    fields
+}
+
+fn cef_key(key: &'static str) -> &'static str {
+    match key {
+        "channel" => "deviceFacility",
+        "user" => "suser",
+        "command" => "cs1",
+        "decision" => "act",
+        "exit_code" => "cn1",
+        "correlation_id" => "cs2",
+        other => other,
+    }
+}
+
+fn leef_key(key: &'static str) -> &'static str {
+    match key {
+        "user" => "usrName",
+        "correlation_id" => "correlationId",
+        "exit_code" => "exitCode",
+        other => other,
+    }
+}
+
+fn event_name(event_type: AuditEventType) -> &'static str {
+    match event_type {
+        AuditEventType::CommandExecution => "Command execution",
+        AuditEventType::FileAccess => "File access",
+        AuditEventType::ConfigChange => "Configuration change",
+        AuditEventType::AuthSuccess => "Authentication succeeded",
+        AuditEventType::AuthFailure => "Authentication failed",
+        AuditEventType::PolicyViolation => "Policy violation",
+        AuditEventType::SecurityEvent => "Security alert",
+    }
+}
+
+/// Position on the CEF/LEEF 0–10 severity scale.
+fn severity_score(severity: AuditSeverity) -> u8 {
+    match severity {
+        AuditSeverity::Info => 3,
+        AuditSeverity::Notice => 5,
+        AuditSeverity::Warning => 7,
+        AuditSeverity::Critical => 10,
+    }
+}
+
+fn escape_cef_header(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('|', "\\|")
+}
+
+fn escape_cef_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('=', "\\=")
+        .replace('\r', "\\r")
+        .replace('\n', "\\n")
+}
+
+/// LEEF has no escaping; the attribute delimiter and line breaks become spaces.
+fn escape_leef_value(value: &str) -> String {
+    value.replace(['\t', '\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocked_command() -> AuditEvent {
+        AuditEvent::new(AuditEventType::CommandExecution)
+            .with_actor("telegram".into(), Some("42".into()), Some("alice".into()))
+            .with_action("echo a=b|c\nrm -rf /".into(), "high".into(), false, false)
+            .with_result(false, Some(1), 3, None)
+    }
+
+    #[test]
+    fn cef_escapes_values_and_maps_severity() {
+        let line = to_cef(&blocked_command());
+        assert!(line.starts_with("CEF:0|ZeroClaw|zeroclaw|"));
+        assert!(line.contains("|command_execution|Command execution|7|"));
+        assert!(line.contains(r"cs1=echo a\=b|c\nrm -rf /"));
+        assert!(line.contains("suser=alice"));
+        assert!(line.contains("act=blocked"));
+        assert!(line.contains("cs1Label=command cs1="));
+        assert!(line.contains("cn1=1"));
+    }
+
+    #[test]
+    fn leef_is_tab_delimited_single_line() {
+        let line = to_leef(&blocked_command());
+        assert!(line.starts_with("LEEF:1.0|ZeroClaw|zeroclaw|"));
+        assert!(!line.contains('\n'));
+        let attributes: Vec<&str> = line.splitn(6, '|').nth(5).unwrap().split('\t').collect();
+        assert!(attributes.contains(&"sev=7"));
+        assert!(attributes.contains(&"usrName=alice"));
+        assert!(attributes.contains(&"command=echo a=b|c rm -rf /"));
+    }
+}
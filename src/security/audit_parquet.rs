@@ -0,0 +1,186 @@
+//! Parquet export of audit events (`audit-parquet` feature).
+//!
+//! [`export_parquet`] writes the same columns as [`export_csv`], typed, so
+//! audit history can be loaded into a data warehouse without a CSV parsing
+//! step. The column layout version is stored per row and in the file's
+//! key-value metadata.
+//!
+//! [`export_csv`]: super::audit::export_csv
+
+use super::audit::{AuditEvent, AUDIT_EXPORT_SCHEMA_VERSION};
+use anyhow::Result;
+use arrow_array::{
+    ArrayRef, BooleanArray, Int32Array, RecordBatch, StringArray, TimestampMicrosecondArray,
+    UInt32Array, UInt64Array,
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+use parquet::format::KeyValue;
+use std::io::Write;
+use std::sync::Arc;
+
+/// File metadata key holding [`AUDIT_EXPORT_SCHEMA_VERSION`].
+pub const SCHEMA_VERSION_METADATA_KEY: &str = "zeroclaw.audit_export_schema_version";
+
+fn text(name: &str) -> Field {
+    Field::new(name, DataType::Utf8, true)
+}
+
+fn export_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("schema_version", DataType::UInt32, false),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+        Field::new("event_id", DataType::Utf8, false),
+        text("correlation_id"),
+        Field::new("event_type", DataType::Utf8, false),
+        text("channel"),
+        text("user_id"),
+        text("username"),
+        text("command"),
+        text("risk_level"),
+        Field::new("approved", DataType::Boolean, true),
+        Field::new("allowed", DataType::Boolean, true),
+        Field::new("success", DataType::Boolean, true),
+        Field::new("exit_code", DataType::Int32, true),
+        Field::new("duration_ms", DataType::UInt64, true),
+        text("error"),
+        Field::new("policy_violation", DataType::Boolean, false),
+        text("sandbox_backend"),
+    ])
+}
+
+/// Write audit events as a single Parquet row group.
+pub fn export_parquet<W: Write + Send>(events: &[AuditEvent], writer: W) -> Result<()> {
+    let schema = Arc::new(export_schema());
+    let strings = |value: fn(&AuditEvent) -> Option<&str>| -> ArrayRef {
+        Arc::new(events.iter().map(value).collect::<StringArray>())
+    };
+    let actions = || events.iter().map(|event| event.action.as_ref());
+    let results = || events.iter().map(|event| event.result.as_ref());
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt32Array::from(vec![
+            AUDIT_EXPORT_SCHEMA_VERSION;
+            events.len()
+        ])),
+        Arc::new(
+            events
+                .iter()
+                .map(|event| Some(event.timestamp.timestamp_micros()))
+                .collect::<TimestampMicrosecondArray>()
+                .with_timezone("UTC"),
+        ),
+        strings(|event| Some(event.event_id.as_str())),
+        strings(|event| event.correlation_id.as_deref()),
+        strings(|event| Some(event.event_type.as_str())),
+        strings(|event| event.actor.as_ref().map(|a| a.channel.as_str())),
+        strings(|event| event.actor.as_ref().and_then(|a| a.user_id.as_deref())),
+        strings(|event| event.actor.as_ref().and_then(|a| a.username.as_deref())),
+        strings(|event| event.action.as_ref().and_then(|a| a.command.as_deref())),
+        strings(|event| event.action.as_ref().and_then(|a| a.risk_level.as_deref())),
+        Arc::new(
+            actions()
+                .map(|a| a.map(|a| a.approved))
+                .collect::<BooleanArray>(),
+        ),
+        Arc::new(
+            actions()
+                .map(|a| a.map(|a| a.allowed))
+                .collect::<BooleanArray>(),
+        ),
+        Arc::new(
+            results()
+                .map(|r| r.map(|r| r.success))
+                .collect::<BooleanArray>(),
+        ),
+        Arc::new(
+            results()
+                .map(|r| r.and_then(|r| r.exit_code))
+                .collect::<Int32Array>(),
+        ),
+        Arc::new(
+            results()
+                .map(|r| r.and_then(|r| r.duration_ms))
+                .collect::<UInt64Array>(),
+        ),
+        strings(|event| event.result.as_ref().and_then(|r| r.error.as_deref())),
+        Arc::new(
+            events
+                .iter()
+                .map(|event| Some(event.security.policy_violation))
+                .collect::<BooleanArray>(),
+        ),
+        strings(|event| event.security.sandbox_backend.as_deref()),
+    ];
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+
+    let properties = WriterProperties::builder()
+        .set_key_value_metadata(Some(vec![KeyValue::new(
+            SCHEMA_VERSION_METADATA_KEY.to_string(),
+            AUDIT_EXPORT_SCHEMA_VERSION.to_string(),
+        )]))
+        .build();
+    let mut writer = ArrowWriter::try_new(writer, schema, Some(properties))?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::audit::{AuditEventType, AUDIT_CSV_COLUMNS};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    #[test]
+    fn parquet_export_round_trips_typed_columns() -> Result<()> {
+        let events = [
+            AuditEvent::new(AuditEventType::CommandExecution)
+                .with_correlation_id("corr-1")
+                .with_actor("telegram".to_string(), Some("42".to_string()), None)
+                .with_action("ls -la".to_string(), "low".to_string(), false, true)
+                .with_result(false, Some(2), 15, Some("exit 2".to_string())),
+            AuditEvent::new(AuditEventType::AuthFailure),
+        ];
+        let file = tempfile::tempfile()?;
+        export_parquet(&events, file.try_clone()?)?;
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file)?;
+        let metadata = builder.metadata().file_metadata().key_value_metadata();
+        assert!(metadata.is_some_and(|entries| entries.iter().any(|entry| {
+            entry.key == SCHEMA_VERSION_METADATA_KEY
+                && entry.value.as_deref() == Some(&AUDIT_EXPORT_SCHEMA_VERSION.to_string())
+        })));
+        let names: Vec<String> = builder
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().clone())
+            .collect();
+        assert_eq!(names, AUDIT_CSV_COLUMNS);
+
+        let batch = builder.build()?.next().unwrap()?;
+        assert_eq!(batch.num_rows(), 2);
+        let column = |name: &str| batch.column_by_name(name).unwrap().clone();
+        assert_eq!(
+            column("correlation_id").as_string::<i32>().value(0),
+            "corr-1"
+        );
+        assert!(column("correlation_id").is_null(1));
+        assert_eq!(
+            column("event_type").as_string::<i32>().value(1),
+            "auth_failure"
+        );
+        assert_eq!(column("exit_code").as_primitive::<Int32Type>().value(0), 2);
+        assert!(!column("success").as_boolean().value(0));
+        assert!(column("command").is_null(1));
+        Ok(())
+    }
+}
@@ -0,0 +1,342 @@
+//! Aggregate statistics over the audit trail for periodic security review.
+//!
+//! [`AuditStats`] summarizes any slice of events; [`report`] queries a
+//! logger for a trailing period and renders the result as Markdown.
+
+use super::audit::{AuditEvent, AuditEventType, AuditLogger, AuditQuery};
+use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+
+/// Number of entries kept in the most-blocked-commands list.
+const TOP_BLOCKED_LIMIT: usize = 10;
+
+/// Command executions by one user on one day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UserDayCount {
+    pub user: String,
+    pub day: NaiveDate,
+    pub commands: usize,
+}
+
+/// A command denied by policy and how often it was attempted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BlockedCommand {
+    pub command: String,
+    pub count: usize,
+}
+
+/// Aggregated view of a set of audit events.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AuditStats {
+    pub total_events: usize,
+    pub commands: usize,
+    pub failed_commands: usize,
+    /// Sorted by user, then day
+    pub commands_per_user_day: Vec<UserDayCount>,
+    /// Most frequently blocked first
+    pub top_blocked: Vec<BlockedCommand>,
+    /// Event counts per day, keyed by event type name
+    pub event_types_per_day: BTreeMap<NaiveDate, BTreeMap<String, usize>>,
+}
+
+impl AuditStats {
+    pub fn from_events(events: &[AuditEvent]) -> Self {
+        let mut stats = Self {
+            total_events: events.len(),
+            ..Self::default()
+        };
+        let mut per_user_day: BTreeMap<(String, NaiveDate), usize> = BTreeMap::new();
+        let mut blocked: HashMap<&str, usize> = HashMap::new();
+
+        for event in events {
+            let day = event.timestamp.date_naive();
+            *stats
+                .event_types_per_day
+                .entry(day)
+                .or_default()
+                .entry(event.event_type.as_str().to_string())
+                .or_default() += 1;
+
+            if let Some(command) = blocked_command(event) {
+                *blocked.entry(command).or_default() += 1;
+            }
+
+            if !matches!(event.event_type, AuditEventType::CommandExecution) {
+                continue;
+            }
+            stats.commands += 1;
+            if event.result.as_ref().is_some_and(|result| !result.success) {
+                stats.failed_commands += 1;
+            }
+            *per_user_day.entry((user_label(event), day)).or_default() += 1;
+        }
+
+        stats.commands_per_user_day = per_user_day
+            .into_iter()
+            .map(|((user, day), commands)| UserDayCount {
+                user,
+                day,
+                commands,
+            })
+            .collect();
+
+        let mut top_blocked: Vec<BlockedCommand> = blocked
+            .into_iter()
+            .map(|(command, count)| BlockedCommand {
+                command: command.to_string(),
+                count,
+            })
+            .collect();
+        top_blocked.sort_by(|a, b| b.count.cmp(&a.count).then(a.command.cmp(&b.command)));
+        top_blocked.truncate(TOP_BLOCKED_LIMIT);
+        stats.top_blocked = top_blocked;
+        stats
+    }
+
+    /// Share of command executions that failed (0.0 when there were none).
+    pub fn failure_rate(&self) -> f64 {
+        if self.commands == 0 {
+            0.0
+        } else {
+            self.failed_commands as f64 / self.commands as f64
+        }
+    }
+}
+
+/// The command a policy refused, for policy violations and denied command
+/// executions. Other events with `allowed = false` (such as anomaly alerts
+/// about commands that did run) are not blocks.
+fn blocked_command(event: &AuditEvent) -> Option<&str> {
+    let action = event.action.as_ref()?;
+    let blocked = match event.event_type {
+        AuditEventType::PolicyViolation => true,
+        AuditEventType::CommandExecution => !action.allowed,
+        _ => false,
+    };
+    blocked.then_some(action.command.as_deref()).flatten()
+}
+
+/// Summary document for one review period.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditReport {
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub stats: AuditStats,
+}
+
+impl AuditReport {
+    /// Render the report as Markdown for a weekly security review.
+    pub fn to_markdown(&self) -> String {
+        let stats = &self.stats;
+        let mut out = format!(
+            "# Audit report {} – {}\n\n",
+            self.period_start.format("%Y-%m-%d %H:%M UTC"),
+            self.period_end.format("%Y-%m-%d %H:%M UTC")
+        );
+        let _ = writeln!(out, "- Events: {}", stats.total_events);
+        let _ = writeln!(
+            out,
+            "- Commands: {} ({} failed, {:.1}% failure rate)",
+            stats.commands,
+            stats.failed_commands,
+            stats.failure_rate() * 100.0
+        );
+
+        out.push_str("\n## Commands per user per day\n\n");
+        if stats.commands_per_user_day.is_empty() {
+            out.push_str("_none_\n");
+        } else {
+            out.push_str("| User | Day | Commands |\n|---|---|---|\n");
+            for row in &stats.commands_per_user_day {
+                let _ = writeln!(out, "| {} | {} | {} |", row.user, row.day, row.commands);
+            }
+        }
+
+        out.push_str("\n## Most blocked commands\n\n");
+        if stats.top_blocked.is_empty() {
+            out.push_str("_none_\n");
+        } else {
+            for (rank, blocked) in stats.top_blocked.iter().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "{}. `{}` ({}×)",
+                    rank + 1,
+                    blocked.command,
+                    blocked.count
+                );
+            }
+        }
+
+        out.push_str("\n## Event types per day\n\n");
+        if stats.event_types_per_day.is_empty() {
+            out.push_str("_none_\n");
+        } else {
+            for (day, counts) in &stats.event_types_per_day {
+                let counts = counts
+                    .iter()
+                    .map(|(event_type, count)| format!("{event_type}={count}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let _ = writeln!(out, "- {day}: {counts}");
+            }
+        }
+        out
+    }
+}
+
+/// Build a report over the `period` ending now.
+pub fn report(logger: &AuditLogger, period: Duration) -> Result<AuditReport> {
+    let period_end = Utc::now();
+    let period_start = period_end - period;
+    let events = logger.query(&AuditQuery {
+        since: Some(period_start),
+        until: Some(period_end),
+        ..AuditQuery::default()
+    })?;
+    Ok(AuditReport {
+        period_start,
+        period_end,
+        stats: AuditStats::from_events(&events),
+    })
+}
+
+fn user_label(event: &AuditEvent) -> String {
+    event
+        .actor
+        .as_ref()
+        .map(|actor| {
+            actor
+                .username
+                .clone()
+                .or_else(|| actor.user_id.clone())
+                .unwrap_or_else(|| actor.channel.clone())
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AuditConfig;
+    use tempfile::TempDir;
+
+    fn command(user: &str, cmd: &str, allowed: bool, success: bool) -> AuditEvent {
+        AuditEvent::new(AuditEventType::CommandExecution)
+            .with_actor("cli".into(), None, Some(user.into()))
+            .with_action(cmd.into(), "low".into(), false, allowed)
+            .with_result(success, None, 1, None)
+    }
+
+    #[test]
+    fn stats_aggregate_users_failures_and_blocked_commands() {
+        let events = vec![
+            command("alice", "ls", true, true),
+            command("alice", "rm -rf /", false, false),
+            command("bob", "rm -rf /", false, false),
+            command("bob", "curl evil", false, false),
+            AuditEvent::new(AuditEventType::AuthFailure),
+            // An anomaly alert about a command that ran is not a block.
+            AuditEvent::new(AuditEventType::SecurityEvent).with_action(
+                "strace ls".into(),
+                "high".into(),
+                true,
+                false,
+            ),
+        ];
+        let stats = AuditStats::from_events(&events);
+
+        assert_eq!(stats.total_events, 6);
+        assert_eq!(stats.commands, 4);
+        assert!((stats.failure_rate() - 0.75).abs() < f64::EPSILON);
+        assert_eq!(
+            stats
+                .commands_per_user_day
+                .iter()
+                .map(|row| (row.user.as_str(), row.commands))
+                .collect::<Vec<_>>(),
+            vec![("alice", 2), ("bob", 2)],
+            "all events share a day unless the test straddles midnight"
+        );
+        assert_eq!(
+            stats.top_blocked[0],
+            BlockedCommand {
+                command: "rm -rf /".into(),
+                count: 2
+            }
+        );
+        assert_eq!(stats.top_blocked.len(), 2);
+        let type_total = |name: &str| -> usize {
+            stats
+                .event_types_per_day
+                .values()
+                .filter_map(|counts| counts.get(name))
+                .sum()
+        };
+        assert_eq!(type_total("command_execution"), 4);
+        assert_eq!(type_total("auth_failure"), 1);
+    }
+
+    #[test]
+    fn report_covers_trailing_period_and_renders_markdown() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = AuditLogger::new(
+            AuditConfig {
+                enabled: true,
+                ..AuditConfig::default()
+            },
+            tmp.path().to_path_buf(),
+        )?;
+        logger.log(&command("alice", "sudo su", false, false))?;
+
+        let report = report(&logger, Duration::days(7))?;
+        assert_eq!(report.stats.commands, 1);
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("- Commands: 1 (1 failed, 100.0% failure rate)"));
+        assert!(markdown.contains("1. `sudo su` (1×)"));
+        assert!(markdown.contains("| alice |"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn report_counts_shell_denials_as_blocked() -> Result<()> {
+        use crate::runtime::NativeRuntime;
+        use crate::security::{AutonomyLevel, SecurityPolicy};
+        use crate::tools::{ShellTool, Tool};
+        use std::sync::Arc;
+
+        let tmp = TempDir::new()?;
+        let logger = Arc::new(AuditLogger::new(
+            AuditConfig {
+                enabled: true,
+                ..AuditConfig::default()
+            },
+            tmp.path().to_path_buf(),
+        )?);
+        let shell = ShellTool::new(
+            Arc::new(SecurityPolicy {
+                autonomy: AutonomyLevel::Supervised,
+                workspace_dir: tmp.path().to_path_buf(),
+                ..SecurityPolicy::default()
+            }),
+            Arc::new(NativeRuntime::new()),
+        )
+        .with_audit(Some(logger.clone()));
+        let result = shell
+            .execute(serde_json::json!({"command": "rm -rf /"}))
+            .await?;
+        assert!(!result.success);
+
+        let report = report(&logger, Duration::days(7))?;
+        assert_eq!(
+            report.stats.top_blocked,
+            vec![BlockedCommand {
+                command: "rm -rf /".into(),
+                count: 1
+            }]
+        );
+        Ok(())
+    }
+}
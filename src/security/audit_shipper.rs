@@ -0,0 +1,191 @@
+//! Remote shipping of audit events.
+//!
+//! When `[security.audit.remote]` is enabled, [`AuditLogger`] appends every
+//! written event to a spool file next to the audit log. [`AuditShipper`]
+//! periodically claims the spool, POSTs its events to the collector in
+//! batches, and deletes what was delivered. Undelivered events stay on disk,
+//! so collector outages and daemon restarts lose nothing.
+//!
+//! [`AuditLogger`]: super::audit::AuditLogger
+
+use super::audit::spool_path;
+use super::audit_spool::{send_with_retry, SpoolClaim};
+use crate::config::{AuditConfig, AuditRemoteConfig};
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::time::Duration;
+
+const SHIP_TIMEOUT_SECS: u64 = 30;
+const SHIP_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Ships spooled audit events to a remote collector.
+pub struct AuditShipper {
+    config: AuditRemoteConfig,
+    spool: SpoolClaim,
+    client: reqwest::Client,
+}
+
+impl AuditShipper {
+    /// Build a shipper for the audit log configured under `zeroclaw_dir`.
+    pub fn new(audit_config: &AuditConfig, zeroclaw_dir: &Path) -> Self {
+        Self {
+            config: audit_config.remote.clone(),
+            spool: SpoolClaim::new(spool_path(&zeroclaw_dir.join(&audit_config.log_path))),
+            client: crate::config::build_runtime_proxy_client_with_timeouts(
+                "security.audit_remote",
+                SHIP_TIMEOUT_SECS,
+                SHIP_CONNECT_TIMEOUT_SECS,
+            ),
+        }
+    }
+
+    /// Ship pending events every `flush_interval_secs` until cancelled.
+    pub async fn run(self) -> Result<()> {
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.flush_interval_secs.max(1)));
+        loop {
+            interval.tick().await;
+            match self.flush().await {
+                Ok(0) => {}
+                Ok(shipped) => tracing::debug!("shipped {shipped} audit events"),
+                Err(e) => tracing::warn!("audit shipping deferred: {e:#}"),
+            }
+        }
+    }
+
+    /// Deliver everything currently spooled. Returns the number of events
+    /// shipped; on failure the undelivered remainder is kept for next time.
+    pub async fn flush(&self) -> Result<usize> {
+        let Some(content) = self.spool.claim()? else {
+            return Ok(0);
+        };
+        let lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
+        let mut shipped = 0;
+
+        for batch in lines.chunks(self.config.batch_size.max(1)) {
+            if let Err(e) = self.send_batch(batch).await {
+                self.spool.retain(&lines[shipped..])?;
+                return Err(e);
+            }
+            shipped += batch.len();
+        }
+
+        self.spool.complete()?;
+        Ok(shipped)
+    }
+
+    async fn send_batch(&self, batch: &[&str]) -> Result<()> {
+        let events = batch
+            .iter()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .context("audit spool contains a malformed event")?;
+        let body = serde_json::json!({ "events": events });
+
+        send_with_retry("audit collector", self.config.max_retries, || {
+            let request = self.client.post(self.config.url.trim()).json(&body);
+            match self.config.auth_token.as_deref() {
+                Some(token) => request.bearer_auth(token),
+                None => request,
+            }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::audit::{AuditEvent, AuditEventType, AuditLogger};
+    use tempfile::TempDir;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn remote_config(url: String) -> AuditConfig {
+        AuditConfig {
+            enabled: true,
+            remote: AuditRemoteConfig {
+                enabled: true,
+                url,
+                auth_token: Some("collector-token".into()),
+                batch_size: 2,
+                max_retries: 0,
+                ..AuditRemoteConfig::default()
+            },
+            ..AuditConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn flush_ships_spooled_events_in_batches() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/ingest"))
+            .and(header("authorization", "Bearer collector-token"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let tmp = TempDir::new()?;
+        let config = remote_config(format!("{}/ingest", server.uri()));
+        let logger = AuditLogger::new(config.clone(), tmp.path().to_path_buf())?;
+        for _ in 0..3 {
+            logger.log(&AuditEvent::new(AuditEventType::CommandExecution))?;
+        }
+
+        let shipper = AuditShipper::new(&config, tmp.path());
+        assert_eq!(shipper.flush().await?, 3);
+        assert!(!shipper.spool.spool_path.exists());
+        assert!(!shipper.spool.inflight_path.exists());
+        assert_eq!(shipper.flush().await?, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn flush_keeps_undelivered_events_when_collector_fails() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let tmp = TempDir::new()?;
+        let config = remote_config(server.uri());
+        let logger = AuditLogger::new(config.clone(), tmp.path().to_path_buf())?;
+        logger.log(&AuditEvent::new(AuditEventType::AuthFailure))?;
+
+        let shipper = AuditShipper::new(&config, tmp.path());
+        assert!(shipper.flush().await.is_err());
+        let pending = std::fs::read_to_string(&shipper.spool.inflight_path)?;
+        assert_eq!(pending.lines().count(), 1);
+
+        // Events written during the outage queue up in a fresh spool.
+        logger.log(&AuditEvent::new(AuditEventType::AuthFailure))?;
+        assert!(shipper.spool.spool_path.exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn max_retries_counts_attempts_after_the_first() -> Result<()> {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let tmp = TempDir::new()?;
+        let mut config = remote_config(server.uri());
+        config.remote.max_retries = 1;
+        let logger = AuditLogger::new(config.clone(), tmp.path().to_path_buf())?;
+        logger.log(&AuditEvent::new(AuditEventType::AuthFailure))?;
+
+        let error = AuditShipper::new(&config, tmp.path())
+            .flush()
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("after 2 attempts"));
+        Ok(())
+    }
+}
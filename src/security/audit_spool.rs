@@ -0,0 +1,84 @@
+//! Draining of audit spools.
+//!
+//! [`AuditLogger`] appends events to spool files that daemon-side consumers
+//! ([`AuditShipper`], [`AuditAlerter`]) deliver over HTTP. Both claim a spool
+//! the same way and retry failed requests with the same backoff.
+//!
+//! [`AuditLogger`]: super::audit::AuditLogger
+//! [`AuditShipper`]: super::audit_shipper::AuditShipper
+//! [`AuditAlerter`]: super::audit_alerts::AuditAlerter
+
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const RETRY_BASE_DELAY_MS: u64 = 500;
+
+/// A spool and the `.inflight` file its pending lines are claimed into.
+pub(super) struct SpoolClaim {
+    pub(super) spool_path: PathBuf,
+    pub(super) inflight_path: PathBuf,
+}
+
+impl SpoolClaim {
+    pub(super) fn new(spool_path: PathBuf) -> Self {
+        Self {
+            inflight_path: PathBuf::from(format!("{}.inflight", spool_path.display())),
+            spool_path,
+        }
+    }
+
+    /// Claim the pending lines, or `None` when nothing is pending. The spool
+    /// is renamed so concurrent appenders start a fresh file; lines left by
+    /// a previous partial delivery are claimed first.
+    pub(super) fn claim(&self) -> Result<Option<String>> {
+        if !self.inflight_path.exists() {
+            if !self.spool_path.exists() {
+                return Ok(None);
+            }
+            std::fs::rename(&self.spool_path, &self.inflight_path)
+                .with_context(|| format!("failed to claim {}", self.spool_path.display()))?;
+        }
+        Ok(Some(std::fs::read_to_string(&self.inflight_path)?))
+    }
+
+    /// Keep `lines` for the next claim.
+    pub(super) fn retain(&self, lines: &[&str]) -> Result<()> {
+        std::fs::write(&self.inflight_path, format!("{}\n", lines.join("\n")))?;
+        Ok(())
+    }
+
+    /// Drop the claimed lines once they are handled.
+    pub(super) fn complete(&self) -> Result<()> {
+        std::fs::remove_file(&self.inflight_path)?;
+        Ok(())
+    }
+}
+
+/// Send the request built by `request`, retrying up to `max_retries` more
+/// times with exponential backoff. `target` names the peer in errors.
+pub(super) async fn send_with_retry(
+    target: &str,
+    max_retries: u32,
+    request: impl Fn() -> reqwest::RequestBuilder,
+) -> Result<()> {
+    let attempts = max_retries.saturating_add(1);
+    let mut last_error = None;
+    for attempt in 0..attempts {
+        if attempt > 0 {
+            let delay = RETRY_BASE_DELAY_MS.saturating_mul(1 << (attempt - 1).min(6));
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+        }
+
+        match request().send().await {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            Ok(response) => last_error = Some(format!("{target} returned {}", response.status())),
+            Err(e) => last_error = Some(e.to_string()),
+        }
+    }
+
+    bail!(
+        "{target} unreachable after {attempts} attempts: {}",
+        last_error.unwrap_or_default()
+    )
+}
@@ -0,0 +1,489 @@
+//! SQLite storage backend for audit events (`backend = "sqlite"`).
+//!
+//! Each event is stored verbatim as JSON alongside indexed columns for the
+//! fields [`AuditQuery`] filters on, so queries over months of history do not
+//! need to scan every record. WAL mode and a busy timeout let several
+//! components append to the same database concurrently; chained inserts read
+//! the chain head inside their write transaction, so concurrent writers
+//! cannot fork the hash chain.
+
+use super::audit::{AuditEvent, AuditEventType, AuditQuery, AuditSeverity};
+use anyhow::{Context, Result};
+use chrono::{SecondsFormat, Utc};
+use parking_lot::Mutex;
+use rusqlite::types::Value;
+use rusqlite::{
+    params, params_from_iter, Connection, OpenFlags, OptionalExtension, TransactionBehavior,
+};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How long a writer waits for another connection's lock before failing.
+const SQLITE_BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+/// Minimum time between retention sweeps.
+const RETENTION_SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// SQLite-backed audit event store
+pub struct SqliteAuditStore {
+    conn: Mutex<Connection>,
+    /// Events older than this are deleted; `None` keeps everything.
+    retention: Option<chrono::Duration>,
+    /// When expired events were last deleted.
+    last_sweep: Mutex<Option<Instant>>,
+}
+
+impl SqliteAuditStore {
+    /// Open (or create) the audit database at `db_path`, deleting events
+    /// older than `retention_days` (0 = keep everything).
+    pub fn open(db_path: &Path, retention_days: u32) -> Result<Self> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create audit directory: {}", parent.display())
+            })?;
+        }
+        let conn = Connection::open(db_path)
+            .with_context(|| format!("Failed to open audit DB: {}", db_path.display()))?;
+        conn.busy_timeout(SQLITE_BUSY_TIMEOUT)?;
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             PRAGMA synchronous  = NORMAL;
+             CREATE TABLE IF NOT EXISTS audit_events (
+                seq             INTEGER PRIMARY KEY AUTOINCREMENT,
+                event_id        TEXT NOT NULL UNIQUE,
+                timestamp       TEXT NOT NULL,
+                correlation_id  TEXT,
+                user_id         TEXT,
+                username        TEXT,
+                command         TEXT,
+                exit_code       INTEGER,
+                sandbox_backend TEXT,
+                hash            TEXT,
+                event           TEXT NOT NULL
+             );
+             CREATE INDEX IF NOT EXISTS idx_audit_timestamp ON audit_events(timestamp);
+             CREATE INDEX IF NOT EXISTS idx_audit_user_id ON audit_events(user_id);
+             CREATE INDEX IF NOT EXISTS idx_audit_username ON audit_events(username);
+             CREATE INDEX IF NOT EXISTS idx_audit_exit_code ON audit_events(exit_code);
+             CREATE INDEX IF NOT EXISTS idx_audit_correlation ON audit_events(correlation_id);",
+        )
+        .context("Failed to initialize audit schema")?;
+
+        let store = Self {
+            conn: Mutex::new(conn),
+            retention: (retention_days > 0)
+                .then(|| chrono::Duration::days(i64::from(retention_days))),
+            last_sweep: Mutex::new(None),
+        };
+        store.sweep_expired(&store.conn.lock())?;
+        Ok(store)
+    }
+
+    /// Open an existing audit database without creating or modifying it.
+    pub fn open_read_only(db_path: &Path) -> Result<Self> {
+        let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .with_context(|| format!("Failed to open audit DB: {}", db_path.display()))?;
+        conn.busy_timeout(SQLITE_BUSY_TIMEOUT)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            retention: None,
+            last_sweep: Mutex::new(None),
+        })
+    }
+
+    /// Store `event`; `json` is its serialized form, kept verbatim so hashes
+    /// and signatures still verify after a round trip.
+    pub fn insert(&self, event: &AuditEvent, json: &str) -> Result<()> {
+        let conn = self.conn.lock();
+        insert_row(&conn, event, json)?;
+        self.sweep_expired(&conn)
+    }
+
+    /// Store the event built by `seal` from the hash of the most recently
+    /// stored event. The head is read and the event written in one
+    /// immediate transaction, so another process appending to the same
+    /// database cannot fork the chain. Returns the sealed event and its JSON.
+    pub fn insert_chained(
+        &self,
+        seal: impl FnOnce(Option<String>) -> Result<(AuditEvent, String)>,
+    ) -> Result<(AuditEvent, String)> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let (event, json) = seal(last_hash(&tx)?)?;
+        insert_row(&tx, &event, &json)?;
+        tx.commit()?;
+        self.sweep_expired(&conn)?;
+        Ok((event, json))
+    }
+
+    /// Delete events past the retention period, at most once per
+    /// [`RETENTION_SWEEP_INTERVAL`].
+    fn sweep_expired(&self, conn: &Connection) -> Result<()> {
+        let Some(retention) = self.retention else {
+            return Ok(());
+        };
+        let mut last_sweep = self.last_sweep.lock();
+        if last_sweep.is_some_and(|at| at.elapsed() < RETENTION_SWEEP_INTERVAL) {
+            return Ok(());
+        }
+        let removed = conn.execute(
+            "DELETE FROM audit_events WHERE timestamp < ?1",
+            [sortable_timestamp(Utc::now() - retention)],
+        )?;
+        if removed > 0 {
+            tracing::debug!("Deleted {removed} audit events past the retention period");
+        }
+        *last_sweep = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Events matching `query` in insertion order, with pagination applied.
+    pub fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEvent>> {
+        let mut sql = String::from("SELECT event FROM audit_events WHERE 1 = 1");
+        let mut args: Vec<Value> = Vec::new();
+
+        if let Some(since) = query.since {
+            sql.push_str(" AND timestamp >= ?");
+            args.push(Value::Text(sortable_timestamp(since)));
+        }
+        if let Some(until) = query.until {
+            sql.push_str(" AND timestamp < ?");
+            args.push(Value::Text(sortable_timestamp(until)));
+        }
+        if let Some(user) = query.user.as_deref() {
+            sql.push_str(" AND (user_id = ? OR username = ?)");
+            args.push(Value::Text(user.to_string()));
+            args.push(Value::Text(user.to_string()));
+        }
+        if let Some(code) = query.exit_code {
+            sql.push_str(" AND exit_code = ?");
+            args.push(Value::Integer(i64::from(code)));
+        }
+        match query.sandboxed {
+            Some(true) => sql.push_str(" AND sandbox_backend IS NOT NULL"),
+            Some(false) => sql.push_str(" AND sandbox_backend IS NULL"),
+            None => {}
+        }
+        if let Some(id) = query.correlation_id.as_deref() {
+            sql.push_str(" AND correlation_id = ?");
+            args.push(Value::Text(id.to_string()));
+        }
+        if let Some(needle) = query.command_contains.as_deref() {
+            // instr() keeps the match case-sensitive, like AuditQuery::matches.
+            sql.push_str(" AND instr(command, ?) > 0");
+            args.push(Value::Text(needle.to_string()));
+        }
+        if let Some(event_type) = query.event_type {
+            sql.push_str(" AND json_extract(event, '$.event_type') = ?");
+            args.push(Value::Text(event_type.as_str().to_string()));
+        }
+        if let Some(channel) = query.channel.as_deref() {
+            sql.push_str(" AND json_extract(event, '$.actor.channel') = ?");
+            args.push(Value::Text(channel.to_string()));
+        }
+        if let Some(severity) = query.min_severity {
+            sql.push_str(" AND ");
+            sql.push_str(&severity_rank_sql());
+            sql.push_str(" >= ?");
+            args.push(Value::Integer(severity as i64));
+        }
+        sql.push_str(" ORDER BY seq LIMIT ? OFFSET ?");
+        args.push(Value::Integer(
+            query
+                .limit
+                .map_or(-1, |limit| i64::try_from(limit).unwrap_or(i64::MAX)),
+        ));
+        args.push(Value::Integer(
+            i64::try_from(query.offset).unwrap_or(i64::MAX),
+        ));
+
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(args), |row| row.get::<_, String>(0))?;
+        rows.map(|row| Ok(serde_json::from_str(&row?)?)).collect()
+    }
+
+    /// Stream every event in insertion order into `visit` until it returns
+    /// `false`.
+    pub fn for_each_event(&self, mut visit: impl FnMut(AuditEvent) -> bool) -> Result<()> {
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare("SELECT event FROM audit_events ORDER BY seq")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        for row in rows {
+            if !visit(serde_json::from_str(&row?)?) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// The `limit` most recently inserted events, oldest first.
+    pub fn recent(&self, limit: usize) -> Result<Vec<AuditEvent>> {
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(
+            "SELECT event FROM \
+             (SELECT seq, event FROM audit_events ORDER BY seq DESC LIMIT ?1) \
+             ORDER BY seq",
+        )?;
+        let rows = stmt.query_map([i64::try_from(limit).unwrap_or(i64::MAX)], |row| {
+            row.get::<_, String>(0)
+        })?;
+        rows.map(|row| Ok(serde_json::from_str(&row?)?)).collect()
+    }
+}
+
+/// Insert one event row; `json` is stored verbatim.
+fn insert_row(conn: &Connection, event: &AuditEvent, json: &str) -> Result<()> {
+    let actor = event.actor.as_ref();
+    conn.execute(
+        "INSERT INTO audit_events
+                (event_id, timestamp, correlation_id, user_id, username, command,
+                 exit_code, sandbox_backend, hash, event)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            event.event_id,
+            sortable_timestamp(event.timestamp),
+            event.correlation_id,
+            actor.and_then(|a| a.user_id.as_deref()),
+            actor.and_then(|a| a.username.as_deref()),
+            event.action.as_ref().and_then(|a| a.command.as_deref()),
+            event.result.as_ref().and_then(|r| r.exit_code),
+            event.security.sandbox_backend,
+            event.hash,
+            json,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Hash of the most recently stored event.
+fn last_hash(conn: &Connection) -> Result<Option<String>> {
+    let hash = conn
+        .query_row(
+            "SELECT hash FROM audit_events ORDER BY seq DESC LIMIT 1",
+            [],
+            |row| row.get::<_, Option<String>>(0),
+        )
+        .optional()?;
+    Ok(hash.flatten())
+}
+
+/// SQL expression ranking an event's severity like [`AuditEvent::severity`],
+/// as the [`AuditSeverity`] discriminant.
+fn severity_rank_sql() -> String {
+    let event_type = "json_extract(event, '$.event_type')";
+    format!(
+        "CASE \
+         WHEN {event_type} = '{security}' THEN {critical} \
+         WHEN {event_type} IN ('{violation}', '{auth_failure}') THEN {warning} \
+         WHEN json_extract(event, '$.action.allowed') = 0 THEN {warning} \
+         WHEN {event_type} = '{config}' THEN {notice} \
+         WHEN json_extract(event, '$.result.success') = 0 THEN {notice} \
+         ELSE {info} END",
+        security = AuditEventType::SecurityEvent.as_str(),
+        violation = AuditEventType::PolicyViolation.as_str(),
+        auth_failure = AuditEventType::AuthFailure.as_str(),
+        config = AuditEventType::ConfigChange.as_str(),
+        critical = AuditSeverity::Critical as i64,
+        warning = AuditSeverity::Warning as i64,
+        notice = AuditSeverity::Notice as i64,
+        info = AuditSeverity::Info as i64,
+    )
+}
+
+/// Fixed-width RFC 3339 (UTC, microseconds) so text order equals time order.
+fn sortable_timestamp(timestamp: chrono::DateTime<chrono::Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AuditBackend, AuditConfig};
+    use crate::security::audit::{AuditEventType, AuditLogger, AuditSeverity};
+    use chrono::{Duration as ChronoDuration, Utc};
+    use tempfile::TempDir;
+
+    fn sqlite_logger(dir: &Path, hash_chain: bool) -> Result<AuditLogger> {
+        let config = AuditConfig {
+            enabled: true,
+            backend: AuditBackend::Sqlite,
+            hash_chain,
+            ..AuditConfig::default()
+        };
+        AuditLogger::new(config, dir.to_path_buf())
+    }
+
+    #[test]
+    fn sqlite_backend_filters_and_paginates() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = sqlite_logger(tmp.path(), false)?;
+        for (user, code) in [("alice", 0), ("bob", 1), ("alice", 1), ("alice", 0)] {
+            logger.log(
+                &AuditEvent::new(AuditEventType::CommandExecution)
+                    .with_actor("cli".into(), Some(user.into()), None)
+                    .with_action(format!("run {user}"), "low".into(), false, true)
+                    .with_result(code == 0, Some(code), 1, None),
+            )?;
+        }
+        assert!(!tmp.path().join("audit.log").exists());
+        assert!(tmp.path().join("audit.db").exists());
+
+        let alice = logger.query(&AuditQuery {
+            user: Some("alice".into()),
+            ..AuditQuery::default()
+        })?;
+        assert_eq!(alice.len(), 3);
+
+        let failed_page = logger.query(&AuditQuery {
+            exit_code: Some(1),
+            offset: 1,
+            limit: Some(5),
+            ..AuditQuery::default()
+        })?;
+        assert_eq!(failed_page.len(), 1);
+        assert_eq!(
+            failed_page[0].action.as_ref().unwrap().command.as_deref(),
+            Some("run alice")
+        );
+
+        let future = logger.query(&AuditQuery {
+            since: Some(Utc::now() + ChronoDuration::hours(1)),
+            ..AuditQuery::default()
+        })?;
+        assert!(future.is_empty());
+
+        let by_command = logger.query(&AuditQuery {
+            command_contains: Some("bob".into()),
+            ..AuditQuery::default()
+        })?;
+        assert_eq!(by_command.len(), 1);
+
+        // Failed commands rank as notices; the offset counts only events
+        // that pass the severity filter.
+        let failures = logger.query(&AuditQuery {
+            event_type: Some(AuditEventType::CommandExecution),
+            min_severity: Some(AuditSeverity::Notice),
+            channel: Some("cli".into()),
+            offset: 1,
+            ..AuditQuery::default()
+        })?;
+        assert_eq!(failures.len(), 1);
+        assert_eq!(
+            failures[0].actor.as_ref().unwrap().user_id.as_deref(),
+            Some("alice")
+        );
+        assert!(logger
+            .query(&AuditQuery {
+                channel: Some("telegram".into()),
+                ..AuditQuery::default()
+            })?
+            .is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn sqlite_backend_resumes_hash_chain() -> Result<()> {
+        let tmp = TempDir::new()?;
+        sqlite_logger(tmp.path(), true)?.log(&AuditEvent::new(AuditEventType::AuthSuccess))?;
+        let reopened = sqlite_logger(tmp.path(), true)?;
+        reopened.log(&AuditEvent::new(AuditEventType::AuthSuccess))?;
+
+        let events = reopened.query(&AuditQuery::default())?;
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].prev_hash, events[0].hash);
+        assert_eq!(
+            events[1].hash.as_deref(),
+            Some(events[1].compute_hash()?.as_str())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sqlite_writers_sharing_a_database_keep_one_chain() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let first = sqlite_logger(tmp.path(), true)?;
+        let second = sqlite_logger(tmp.path(), true)?;
+        for _ in 0..3 {
+            first.log(&AuditEvent::new(AuditEventType::CommandExecution))?;
+            second.log(&AuditEvent::new(AuditEventType::CommandExecution))?;
+        }
+
+        assert_eq!(first.verify_history(None)?, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn sqlite_retention_deletes_expired_events() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = sqlite_logger(tmp.path(), false)?;
+        let mut expired = AuditEvent::new(AuditEventType::CommandExecution);
+        expired.timestamp = Utc::now() - ChronoDuration::days(10);
+        logger.log(&expired)?;
+        logger.log(&AuditEvent::new(AuditEventType::CommandExecution))?;
+        drop(logger);
+
+        let config = AuditConfig {
+            enabled: true,
+            backend: AuditBackend::Sqlite,
+            retention_days: 7,
+            ..AuditConfig::default()
+        };
+        let logger = AuditLogger::new(config, tmp.path().to_path_buf())?;
+        let events = logger.query(&AuditQuery::default())?;
+        assert_eq!(events.len(), 1);
+        assert!(events[0].timestamp > Utc::now() - ChronoDuration::days(1));
+        Ok(())
+    }
+
+    #[test]
+    fn sqlite_recent_returns_the_newest_events_in_order() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let config = AuditConfig {
+            enabled: true,
+            backend: AuditBackend::Sqlite,
+            memory_buffer_events: 0,
+            ..AuditConfig::default()
+        };
+        let logger = AuditLogger::new(config, tmp.path().to_path_buf())?;
+        for i in 1..=5 {
+            logger.log(
+                &AuditEvent::new(AuditEventType::CommandExecution).with_action(
+                    format!("e{i}"),
+                    "low".into(),
+                    false,
+                    true,
+                ),
+            )?;
+        }
+
+        let commands: Vec<String> = logger
+            .recent(3)?
+            .into_iter()
+            .filter_map(|event| event.action.and_then(|action| action.command))
+            .collect();
+        assert_eq!(commands, ["e3", "e4", "e5"]);
+        Ok(())
+    }
+
+    #[test]
+    fn sqlite_history_verifies_read_only() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let logger = sqlite_logger(tmp.path(), true)?;
+        for _ in 0..3 {
+            logger.log(&AuditEvent::new(AuditEventType::CommandExecution))?;
+        }
+        drop(logger);
+
+        let config = AuditConfig {
+            backend: AuditBackend::Sqlite,
+            hash_chain: true,
+            ..AuditConfig::default()
+        };
+        let reader = AuditLogger::open_read_only(config, tmp.path())?;
+        assert_eq!(reader.verify_history(None)?, 3);
+        // Writes through a read-only logger are dropped.
+        reader.log(&AuditEvent::new(AuditEventType::CommandExecution))?;
+        assert_eq!(reader.verify_history(None)?, 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,424 @@
+//! Syslog output for audit events.
+//!
+//! [`SyslogSink`] renders each event as an RFC 5424 message and sends it over
+//! UDP, TCP (octet-counted framing per RFC 6587), or a Unix datagram socket
+//! such as `/dev/log`. Messages are handed to a bounded queue drained by a
+//! dedicated thread, so a slow or unreachable collector never blocks the
+//! audit write path; when the queue is full, events are dropped from syslog
+//! (they are still in the local log). Connections are opened lazily and
+//! re-established after a failed send, so a syslog daemon restart only costs
+//! the events sent while it was down.
+
+use super::audit::{AuditEvent, AuditSeverity};
+use super::audit_format;
+use crate::config::{AuditEventFormat, AuditSyslogConfig};
+use anyhow::{bail, Context, Result};
+use chrono::SecondsFormat;
+use std::io::Write;
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs, UdpSocket};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// RFC 5424 facility 13, "log audit".
+const SYSLOG_FACILITY_LOG_AUDIT: u8 = 13;
+
+/// Upper bound on connecting to and writing to a TCP collector, so a stalled
+/// collector only delays the sender thread.
+const TCP_IO_TIMEOUT_SECS: u64 = 2;
+
+/// Messages waiting for the sender thread before new ones are dropped.
+const SYSLOG_QUEUE_CAPACITY: usize = 1024;
+
+/// Where messages go, as parsed from `address`.
+enum Destination {
+    Udp(String),
+    Tcp(String),
+    Unix(PathBuf),
+}
+
+impl Destination {
+    fn parse(address: &str) -> Result<Self> {
+        let address = address.trim();
+        if let Some(target) = address.strip_prefix("udp://") {
+            Ok(Self::Udp(parse_host_port(target)?))
+        } else if let Some(target) = address.strip_prefix("tcp://") {
+            Ok(Self::Tcp(parse_host_port(target)?))
+        } else if let Some(path) = address.strip_prefix("unix://") {
+            if path.is_empty() {
+                bail!("syslog address {address} has no socket path");
+            }
+            if cfg!(not(unix)) {
+                bail!("unix:// syslog addresses are only supported on Unix platforms");
+            }
+            Ok(Self::Unix(path.into()))
+        } else {
+            bail!("syslog address must start with udp://, tcp://, or unix://: {address}");
+        }
+    }
+}
+
+/// A resolved destination and its lazily opened socket. Owned by the sender
+/// thread.
+enum Transport {
+    Udp {
+        addr: SocketAddr,
+        socket: Option<UdpSocket>,
+    },
+    Tcp {
+        target: String,
+        addr: SocketAddr,
+        stream: Option<TcpStream>,
+    },
+    #[cfg(unix)]
+    Unix {
+        path: PathBuf,
+        socket: Option<std::os::unix::net::UnixDatagram>,
+    },
+}
+
+impl Transport {
+    fn deliver(&mut self, message: &str) -> Result<()> {
+        match self {
+            Self::Udp { addr, socket } => {
+                if socket.is_none() {
+                    let bind = if addr.is_ipv4() {
+                        "0.0.0.0:0"
+                    } else {
+                        "[::]:0"
+                    };
+                    *socket = Some(UdpSocket::bind(bind)?);
+                }
+                let socket = socket.as_ref().expect("socket bound above");
+                socket.send_to(message.as_bytes(), *addr)?;
+            }
+            Self::Tcp {
+                target,
+                addr,
+                stream,
+            } => {
+                let framed = format!("{} {message}", message.len());
+                // A collector restart surfaces as a failed write on the old
+                // connection; reconnect once and resend.
+                for attempt in 0..2 {
+                    if stream.is_none() {
+                        let timeout = Duration::from_secs(TCP_IO_TIMEOUT_SECS);
+                        let connected = TcpStream::connect_timeout(addr, timeout)
+                            .with_context(|| format!("failed to connect to syslog at {target}"))?;
+                        connected.set_write_timeout(Some(timeout))?;
+                        *stream = Some(connected);
+                    }
+                    let written = stream
+                        .as_mut()
+                        .expect("stream connected above")
+                        .write_all(framed.as_bytes());
+                    match written {
+                        Ok(()) => break,
+                        Err(e) => {
+                            *stream = None;
+                            if attempt == 1 {
+                                return Err(e.into());
+                            }
+                        }
+                    }
+                }
+            }
+            #[cfg(unix)]
+            Self::Unix { path, socket } => {
+                if socket.is_none() {
+                    *socket = Some(std::os::unix::net::UnixDatagram::unbound()?);
+                }
+                let sent = socket
+                    .as_ref()
+                    .expect("socket created above")
+                    .send_to(message.as_bytes(), &*path);
+                if let Err(e) = sent {
+                    *socket = None;
+                    return Err(e).with_context(|| format!("failed to send to {}", path.display()));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sends audit events to a syslog daemon or collector.
+pub struct SyslogSink {
+    queue: SyncSender<String>,
+    /// Messages dropped because the queue was full, reported once the
+    /// sender thread catches up.
+    dropped: Arc<AtomicU64>,
+    app_name: String,
+    hostname: String,
+    format: AuditEventFormat,
+    min_severity: AuditSeverity,
+}
+
+impl SyslogSink {
+    /// Check `config` without resolving the address or starting a sender.
+    pub fn validate(config: &AuditSyslogConfig) -> Result<()> {
+        Destination::parse(&config.address)?;
+        if printable_field(&config.app_name, 48) == "-" {
+            bail!("syslog app_name must not be empty");
+        }
+        Ok(())
+    }
+
+    /// Resolve the configured address and start the sender thread. No
+    /// connection is made until the first event is sent.
+    pub fn new(config: &AuditSyslogConfig) -> Result<Self> {
+        Self::validate(config)?;
+        let transport = match Destination::parse(&config.address)? {
+            Destination::Udp(target) => Transport::Udp {
+                addr: resolve(&target)?,
+                socket: None,
+            },
+            Destination::Tcp(target) => Transport::Tcp {
+                addr: resolve(&target)?,
+                target,
+                stream: None,
+            },
+            Destination::Unix(path) => unix_transport(path)?,
+        };
+        let hostname = hostname::get()
+            .map(|host| printable_field(&host.to_string_lossy(), 255))
+            .unwrap_or_else(|_| "-".into());
+
+        let (queue, messages) = mpsc::sync_channel::<String>(SYSLOG_QUEUE_CAPACITY);
+        let dropped = Arc::new(AtomicU64::new(0));
+        let sender_dropped = Arc::clone(&dropped);
+        std::thread::Builder::new()
+            .name("audit-syslog".into())
+            .spawn(move || {
+                let mut transport = transport;
+                // Ends once the sink (the only sender) is dropped.
+                for message in messages {
+                    match transport.deliver(&message) {
+                        Ok(()) => {
+                            let missed = sender_dropped.swap(0, Ordering::Relaxed);
+                            if missed > 0 {
+                                tracing::warn!(
+                                    "Dropped {missed} audit events while the syslog queue was full"
+                                );
+                            }
+                        }
+                        Err(e) => tracing::warn!("Failed to send audit event to syslog: {e:#}"),
+                    }
+                }
+            })
+            .context("failed to start the syslog sender thread")?;
+
+        Ok(Self {
+            queue,
+            dropped,
+            app_name: printable_field(&config.app_name, 48),
+            hostname,
+            format: config.format,
+            min_severity: config.min_severity,
+        })
+    }
+
+    /// Queue `event` for delivery without blocking. `line` is its serialized
+    /// JSON, used as the message body unless a SIEM format is configured.
+    /// Events below `min_severity` are skipped before they are queued.
+    pub fn send(&self, event: &AuditEvent, line: &str) {
+        if event.severity() < self.min_severity {
+            return;
+        }
+        match self.queue.try_send(self.format(event, line)) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                if self.dropped.fetch_add(1, Ordering::Relaxed) == 0 {
+                    tracing::warn!("Syslog queue is full; dropping audit events for syslog");
+                }
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                tracing::warn!("Syslog sender thread has stopped; audit event not sent");
+            }
+        }
+    }
+
+    /// RFC 5424 message: `<PRI>1 TIMESTAMP HOST APP PROCID MSGID - MSG`.
+    fn format(&self, event: &AuditEvent, line: &str) -> String {
+        let body = match self.format {
+            AuditEventFormat::Json => line.to_string(),
+            AuditEventFormat::Cef => audit_format::to_cef(event),
+            AuditEventFormat::Leef => audit_format::to_leef(event),
+        };
+        let pri = SYSLOG_FACILITY_LOG_AUDIT * 8 + syslog_severity(event.severity());
+        format!(
+            "<{pri}>1 {} {} {} {} {} - {body}",
+            event.timestamp.to_rfc3339_opts(SecondsFormat::Micros, true),
+            self.hostname,
+            self.app_name,
+            std::process::id(),
+            event.event_type.as_str(),
+        )
+    }
+}
+
+/// RFC 5424 numeric severity (2 = critical … 6 = informational).
+fn syslog_severity(severity: AuditSeverity) -> u8 {
+    match severity {
+        AuditSeverity::Critical => 2,
+        AuditSeverity::Warning => 4,
+        AuditSeverity::Notice => 5,
+        AuditSeverity::Info => 6,
+    }
+}
+
+fn parse_host_port(target: &str) -> Result<String> {
+    let Some((host, port)) = target.rsplit_once(':') else {
+        bail!("syslog address must include a port: {target}");
+    };
+    if host.is_empty() || port.parse::<u16>().is_err() {
+        bail!("invalid syslog host:port: {target}");
+    }
+    Ok(target.to_string())
+}
+
+fn resolve(target: &str) -> Result<SocketAddr> {
+    target
+        .to_socket_addrs()
+        .with_context(|| format!("failed to resolve syslog address {target}"))?
+        .next()
+        .with_context(|| format!("syslog address {target} resolved to nothing"))
+}
+
+#[cfg(unix)]
+#[allow(clippy::unnecessary_wraps)]
+fn unix_transport(path: PathBuf) -> Result<Transport> {
+    Ok(Transport::Unix { path, socket: None })
+}
+
+#[cfg(not(unix))]
+fn unix_transport(_path: PathBuf) -> Result<Transport> {
+    bail!("unix:// syslog addresses are only supported on Unix platforms")
+}
+
+/// Header fields are printable US-ASCII without spaces; anything else is
+/// dropped, and an empty result becomes the NILVALUE `-`.
+fn printable_field(value: &str, max_len: usize) -> String {
+    let field: String = value
+        .chars()
+        .filter(|ch| ch.is_ascii_graphic())
+        .take(max_len)
+        .collect();
+    if field.is_empty() {
+        "-".into()
+    } else {
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::audit::AuditEventType;
+
+    #[test]
+    fn udp_messages_carry_severity_and_event_json() -> Result<()> {
+        let collector = UdpSocket::bind("127.0.0.1:0")?;
+        collector.set_read_timeout(Some(Duration::from_secs(5)))?;
+        let sink = SyslogSink::new(&AuditSyslogConfig {
+            enabled: true,
+            address: format!("udp://{}", collector.local_addr()?),
+            app_name: "zero claw".into(),
+            min_severity: AuditSeverity::Warning,
+            ..AuditSyslogConfig::default()
+        })?;
+
+        // Below the threshold: never sent, so the first datagram is the failure.
+        let skipped = AuditEvent::new(AuditEventType::AuthSuccess);
+        sink.send(&skipped, &serde_json::to_string(&skipped)?);
+        let event = AuditEvent::new(AuditEventType::AuthFailure);
+        let line = serde_json::to_string(&event)?;
+        sink.send(&event, &line);
+
+        let mut buf = [0u8; 4096];
+        let len = collector.recv(&mut buf)?;
+        let message = std::str::from_utf8(&buf[..len])?;
+        // facility 13 * 8 + warning (4)
+        assert!(message.starts_with("<108>1 "), "{message}");
+        assert!(message.contains(" zeroclaw "));
+        assert!(message.contains(" auth_failure - {"));
+        assert!(message.ends_with(&line));
+        Ok(())
+    }
+
+    /// Read one octet-counted frame: `<len> <message>`.
+    fn read_frame(stream: &mut TcpStream) -> Result<String> {
+        use std::io::Read;
+        let mut len = String::new();
+        let mut byte = [0u8; 1];
+        loop {
+            stream.read_exact(&mut byte)?;
+            if byte[0] == b' ' {
+                break;
+            }
+            len.push(char::from(byte[0]));
+        }
+        let mut message = vec![0u8; len.parse()?];
+        stream.read_exact(&mut message)?;
+        Ok(String::from_utf8(message)?)
+    }
+
+    #[test]
+    fn tcp_reconnects_after_collector_drops_connection() -> Result<()> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let sink = SyslogSink::new(&AuditSyslogConfig {
+            enabled: true,
+            address: format!("tcp://{}", listener.local_addr()?),
+            min_severity: AuditSeverity::Warning,
+            ..AuditSyslogConfig::default()
+        })?;
+        let event = AuditEvent::new(AuditEventType::AuthFailure);
+        let line = serde_json::to_string(&event)?;
+
+        sink.send(&event, &line);
+        let (mut first, _) = listener.accept()?;
+        first.set_read_timeout(Some(Duration::from_secs(5)))?;
+        assert!(read_frame(&mut first)?.ends_with(&line));
+        // Simulate a collector restart.
+        drop(first);
+
+        // The first write after the close may still land in the dead
+        // socket's buffer; keep sending until the sink reconnects.
+        listener.set_nonblocking(true)?;
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        let mut second = loop {
+            sink.send(&event, &line);
+            match listener.accept() {
+                Ok((stream, _)) => break stream,
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    assert!(
+                        std::time::Instant::now() < deadline,
+                        "sink never reconnected"
+                    );
+                    std::thread::sleep(Duration::from_millis(50));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        };
+        second.set_nonblocking(false)?;
+        second.set_read_timeout(Some(Duration::from_secs(5)))?;
+        let message = read_frame(&mut second)?;
+        assert!(message.starts_with("<108>1 "), "{message}");
+        assert!(message.ends_with(&line));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_addresses() {
+        for address in ["syslog.example:514", "udp://syslog.example", "tcp://:514"] {
+            let config = AuditSyslogConfig {
+                enabled: true,
+                address: address.into(),
+                ..AuditSyslogConfig::default()
+            };
+            assert!(SyslogSink::new(&config).is_err(), "{address}");
+        }
+    }
+}
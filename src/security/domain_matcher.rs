@@ -171,7 +171,7 @@ fn domain_matches_pattern(pattern: &str, domain: &str) -> bool {
     wildcard_match(pattern.as_bytes(), domain.as_bytes())
 }
 
-fn wildcard_match(pattern: &[u8], value: &[u8]) -> bool {
+pub(crate) fn wildcard_match(pattern: &[u8], value: &[u8]) -> bool {
     let mut p = 0usize;
     let mut v = 0usize;
     let mut star_idx: Option<usize> = None;
@@ -0,0 +1,189 @@
+//! Workspace snapshots for attributing file changes to commands.
+//!
+//! Capture a [`WorkspaceSnapshot`] before a command runs and another after
+//! it finishes; [`WorkspaceSnapshot::diff`] lists the files the command
+//! created, modified, or deleted so the audit entry can record them.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Files up to this size are content-hashed, so rewrites that preserve size
+/// and mtime are still caught; larger files are compared by metadata only.
+const HASH_MAX_FILE_BYTES: u64 = 1024 * 1024;
+
+/// Directories never descended into: VCS metadata and build output churn on
+/// almost every command and would drown out meaningful changes.
+const SKIPPED_DIRS: &[&str] = &[".git", "target", "node_modules"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChangeKind {
+    Created,
+    Modified,
+    Deleted,
+}
+
+/// A file touched by a command, relative to the workspace root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileChange {
+    pub path: String,
+    pub kind: FileChangeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileState {
+    len: u64,
+    modified: Option<SystemTime>,
+    sha256: Option<[u8; 32]>,
+}
+
+/// Point-in-time metadata (and small-file hashes) for a workspace tree.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceSnapshot {
+    files: BTreeMap<PathBuf, FileState>,
+    /// Set when `max_files` was reached; changes past the cap are not seen.
+    pub truncated: bool,
+}
+
+impl WorkspaceSnapshot {
+    /// Walk `root` (without following symlinks), recording at most
+    /// `max_files` regular files. Unreadable entries are skipped.
+    pub fn capture(root: &Path, max_files: usize) -> Self {
+        let mut snapshot = Self::default();
+        let mut pending = vec![root.to_path_buf()];
+
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                let path = entry.path();
+                if file_type.is_dir() {
+                    let skipped = SKIPPED_DIRS
+                        .iter()
+                        .any(|name| entry.file_name() == std::ffi::OsStr::new(name));
+                    if !skipped {
+                        pending.push(path);
+                    }
+                    continue;
+                }
+                if !file_type.is_file() {
+                    continue;
+                }
+                if snapshot.files.len() >= max_files {
+                    snapshot.truncated = true;
+                    return snapshot;
+                }
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                let sha256 = (metadata.len() <= HASH_MAX_FILE_BYTES)
+                    .then(|| std::fs::read(&path).ok())
+                    .flatten()
+                    .map(|bytes| Sha256::digest(&bytes).into());
+                let relative = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+                snapshot.files.insert(
+                    relative,
+                    FileState {
+                        len: metadata.len(),
+                        modified: metadata.modified().ok(),
+                        sha256,
+                    },
+                );
+            }
+        }
+        snapshot
+    }
+
+    /// Files created, modified, or deleted between `self` and `after`,
+    /// sorted by path.
+    pub fn diff(&self, after: &Self) -> Vec<FileChange> {
+        let mut changes = Vec::new();
+        for (path, state) in &after.files {
+            let kind = match self.files.get(path) {
+                None => FileChangeKind::Created,
+                Some(before) if before != state => FileChangeKind::Modified,
+                Some(_) => continue,
+            };
+            changes.push((path, kind));
+        }
+        for path in self.files.keys() {
+            if !after.files.contains_key(path) {
+                changes.push((path, FileChangeKind::Deleted));
+            }
+        }
+        changes.sort_by(|a, b| a.0.cmp(b.0));
+        changes
+            .into_iter()
+            .map(|(path, kind)| FileChange {
+                path: path.to_string_lossy().into_owned(),
+                kind,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn diff_reports_created_modified_and_deleted_files() -> anyhow::Result<()> {
+        let tmp = TempDir::new()?;
+        std::fs::create_dir_all(tmp.path().join("src"))?;
+        std::fs::create_dir_all(tmp.path().join(".git"))?;
+        std::fs::write(tmp.path().join("src/keep.rs"), "same")?;
+        std::fs::write(tmp.path().join("src/edit.rs"), "v1")?;
+        std::fs::write(tmp.path().join("gone.txt"), "bye")?;
+        let before = WorkspaceSnapshot::capture(tmp.path(), 100);
+
+        std::fs::write(tmp.path().join("src/edit.rs"), "v2")?;
+        std::fs::remove_file(tmp.path().join("gone.txt"))?;
+        std::fs::write(tmp.path().join("new.txt"), "hi")?;
+        std::fs::write(tmp.path().join(".git/index"), "ignored")?;
+        let after = WorkspaceSnapshot::capture(tmp.path(), 100);
+
+        let changes = before.diff(&after);
+        let edit = Path::new("src")
+            .join("edit.rs")
+            .to_string_lossy()
+            .into_owned();
+        assert_eq!(
+            changes,
+            vec![
+                FileChange {
+                    path: "gone.txt".into(),
+                    kind: FileChangeKind::Deleted
+                },
+                FileChange {
+                    path: "new.txt".into(),
+                    kind: FileChangeKind::Created
+                },
+                FileChange {
+                    path: edit,
+                    kind: FileChangeKind::Modified
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn capture_stops_at_file_cap() -> anyhow::Result<()> {
+        let tmp = TempDir::new()?;
+        for i in 0..5 {
+            std::fs::write(tmp.path().join(format!("f{i}")), "x")?;
+        }
+        let snapshot = WorkspaceSnapshot::capture(tmp.path(), 3);
+        assert!(snapshot.truncated);
+        assert_eq!(snapshot.files.len(), 3);
+        Ok(())
+    }
+}
@@ -19,10 +19,22 @@
 //! change guidelines.
 
 pub mod audit;
+pub mod audit_alerts;
+pub mod audit_anonymize;
+pub mod audit_cli;
+pub mod audit_format;
+#[cfg(feature = "audit-parquet")]
+pub mod audit_parquet;
+pub mod audit_report;
+pub mod audit_shipper;
+mod audit_spool;
+pub mod audit_store;
+pub mod audit_syslog;
 #[cfg(feature = "sandbox-bubblewrap")]
 pub mod bubblewrap;
 pub mod detect;
 pub mod docker;
+pub mod file_changes;
 pub mod file_link_guard;
 
 // Prompt injection defense (contributed from RustyClaw, MIT licensed)
@@ -41,11 +53,12 @@ pub mod prompt_guard;
 pub mod roles;
 pub mod secrets;
 pub mod sensitive_paths;
+pub mod session_replay;
 pub mod syscall_anomaly;
 pub mod traits;
 
 #[allow(unused_imports)]
-pub use audit::{AuditEvent, AuditEventType, AuditLogger};
+pub use audit::{AuditEvent, AuditEventType, AuditLogger, AuditQuery};
 #[allow(unused_imports)]
 pub use detect::create_sandbox;
 pub use domain_matcher::DomainMatcher;
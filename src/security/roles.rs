@@ -1,3 +1,4 @@
+use super::domain_matcher::wildcard_match;
 use crate::config::SecurityRoleConfig;
 use anyhow::{anyhow, bail, Result};
 use std::collections::HashMap;
@@ -280,12 +281,21 @@ impl RoleRegistry {
     }
 }
 
+/// Rules match tool names case-insensitively; `*` in a rule matches any run
+/// of characters, so `git*` covers `git_operations`.
 fn matches_tool(rules: &[String], tool_name: &str) -> bool {
+    let tool_name = tool_name.to_ascii_lowercase();
     rules
         .iter()
         .map(|rule| rule.trim())
         .filter(|rule| !rule.is_empty())
-        .any(|rule| rule == "*" || rule.eq_ignore_ascii_case(tool_name))
+        .any(|rule| {
+            if rule.contains('*') {
+                wildcard_match(rule.to_ascii_lowercase().as_bytes(), tool_name.as_bytes())
+            } else {
+                rule.eq_ignore_ascii_case(&tool_name)
+            }
+        })
 }
 
 #[cfg(test)]
@@ -314,6 +324,37 @@ mod tests {
         assert!(!shell.allowed);
     }
 
+    #[test]
+    fn tool_rules_support_glob_patterns() {
+        let registry = RoleRegistry::from_config(&[SecurityRoleConfig {
+            name: "releaser".into(),
+            allowed_tools: vec!["git*".into(), "cron_*".into()],
+            denied_tools: vec!["cron_remove".into()],
+            totp_gated: vec!["git_*".into()],
+            ..SecurityRoleConfig::default()
+        }])
+        .unwrap();
+
+        let git = registry.resolve_tool_access("releaser", "git_operations", &[]);
+        assert!(git.allowed);
+        assert!(git.requires_totp);
+        assert!(
+            registry
+                .resolve_tool_access("releaser", "Cron_Add", &[])
+                .allowed
+        );
+        assert!(
+            !registry
+                .resolve_tool_access("releaser", "cron_remove", &[])
+                .allowed
+        );
+        assert!(
+            !registry
+                .resolve_tool_access("releaser", "shell", &[])
+                .allowed
+        );
+    }
+
     #[test]
     fn owner_uses_global_gated_actions_for_totp() {
         let registry = RoleRegistry::built_in();
@@ -0,0 +1,242 @@
+//! Timeline reconstruction for post-incident review.
+//!
+//! Given a correlation id, [`SessionReplay`] gathers the audit events and
+//! syscall anomaly alerts that share it and orders them into a single
+//! timeline, renderable as JSON or as plain text.
+
+use super::audit::{AuditEvent, AuditEventType, AuditLogger, AuditQuery};
+use super::syscall_anomaly::SyscallAnomalyAlert;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// What a timeline entry records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineEntryKind {
+    Command,
+    Output,
+    SecurityEvent,
+    SyscallAnomaly,
+}
+
+/// One step in a reconstructed session.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineEntry {
+    pub timestamp: DateTime<Utc>,
+    pub kind: TimelineEntryKind,
+    pub summary: String,
+    /// Source record, kept verbatim for tooling that needs every field
+    pub record: serde_json::Value,
+}
+
+/// Ordered timeline for one correlation id.
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionReplay {
+    pub correlation_id: String,
+    pub entries: Vec<TimelineEntry>,
+}
+
+impl SessionReplay {
+    /// Reconstruct the timeline for `correlation_id` from the audit log and,
+    /// when given, the syscall anomaly log.
+    pub fn build(
+        audit: &AuditLogger,
+        anomaly_log: Option<&Path>,
+        correlation_id: &str,
+    ) -> Result<Self> {
+        let mut entries = Vec::new();
+
+        let events = audit.query(&AuditQuery {
+            correlation_id: Some(correlation_id.to_string()),
+            ..AuditQuery::default()
+        })?;
+        for event in &events {
+            entries.extend(audit_entries(event)?);
+        }
+
+        if let Some(path) = anomaly_log.filter(|path| path.exists()) {
+            let reader = BufReader::new(std::fs::File::open(path)?);
+            for line in reader.lines() {
+                let line = line?;
+                let Ok(alert) = serde_json::from_str::<SyscallAnomalyAlert>(&line) else {
+                    continue;
+                };
+                if alert.correlation_id.as_deref() != Some(correlation_id) {
+                    continue;
+                }
+                entries.push(TimelineEntry {
+                    timestamp: alert.timestamp,
+                    kind: TimelineEntryKind::SyscallAnomaly,
+                    summary: format!(
+                        "{:?} syscall={} in `{}`",
+                        alert.kind,
+                        alert.syscall.as_deref().unwrap_or("-"),
+                        alert.command
+                    ),
+                    record: serde_json::to_value(&alert)?,
+                });
+            }
+        }
+
+        // Stable sort keeps a command ahead of its own output at equal times.
+        entries.sort_by_key(|entry| entry.timestamp);
+        Ok(Self {
+            correlation_id: correlation_id.to_string(),
+            entries,
+        })
+    }
+
+    /// Human-readable timeline, one line per entry.
+    pub fn render_text(&self) -> String {
+        let mut out = format!(
+            "Session {} — {} entries\n",
+            self.correlation_id,
+            self.entries.len()
+        );
+        for entry in &self.entries {
+            let _ = writeln!(
+                out,
+                "{}  {:<15}  {}",
+                entry.timestamp.format("%Y-%m-%d %H:%M:%S%.3f"),
+                kind_label(entry.kind),
+                entry.summary
+            );
+        }
+        out
+    }
+}
+
+fn kind_label(kind: TimelineEntryKind) -> &'static str {
+    match kind {
+        TimelineEntryKind::Command => "command",
+        TimelineEntryKind::Output => "output",
+        TimelineEntryKind::SecurityEvent => "security",
+        TimelineEntryKind::SyscallAnomaly => "syscall_anomaly",
+    }
+}
+
+fn audit_entries(event: &AuditEvent) -> Result<Vec<TimelineEntry>> {
+    let record = serde_json::to_value(event)?;
+    let command = event
+        .action
+        .as_ref()
+        .and_then(|action| action.command.as_deref())
+        .unwrap_or("-");
+
+    let (kind, summary) = match event.event_type {
+        AuditEventType::CommandExecution => {
+            let outcome = match event.result.as_ref() {
+                Some(result) => match result.exit_code {
+                    Some(code) => format!("exit {code}"),
+                    None if result.success => "ok".to_string(),
+                    None => "failed".to_string(),
+                },
+                None => "no result".to_string(),
+            };
+            (
+                TimelineEntryKind::Command,
+                format!("`{command}` ({outcome})"),
+            )
+        }
+        _ => (
+            TimelineEntryKind::SecurityEvent,
+            format!("{}: `{command}`", event.event_type.as_str()),
+        ),
+    };
+
+    let mut entries = vec![TimelineEntry {
+        timestamp: event.timestamp,
+        kind,
+        summary,
+        record,
+    }];
+    if let Some(output) = event.output.as_ref() {
+        let first_line = |text: &str| text.lines().next().unwrap_or_default().to_string();
+        entries.push(TimelineEntry {
+            timestamp: event.timestamp,
+            kind: TimelineEntryKind::Output,
+            summary: format!(
+                "stdout: {} | stderr: {}{}",
+                first_line(&output.stdout),
+                first_line(&output.stderr),
+                if output.truncated { " (truncated)" } else { "" }
+            ),
+            record: serde_json::to_value(output)?,
+        });
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AuditConfig, SyscallAnomalyConfig};
+    use crate::security::syscall_anomaly::SyscallAnomalyDetector;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn replay_orders_commands_outputs_and_anomalies() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let audit_config = AuditConfig {
+            enabled: true,
+            capture_output_max_bytes: 1024,
+            ..AuditConfig::default()
+        };
+        let logger = Arc::new(AuditLogger::new(audit_config, tmp.path().to_path_buf())?);
+        logger.log(
+            &AuditEvent::new(AuditEventType::CommandExecution)
+                .with_correlation_id("run-1")
+                .with_action("curl -o x.sh host".into(), "medium".into(), true, true)
+                .with_result(true, Some(0), 12, None)
+                .with_output("saved x.sh\n", ""),
+        )?;
+        logger.log(
+            &AuditEvent::new(AuditEventType::CommandExecution)
+                .with_correlation_id("other")
+                .with_action("ls".into(), "low".into(), false, true),
+        )?;
+
+        let anomaly_config = SyscallAnomalyConfig {
+            baseline_syscalls: vec!["read".into()],
+            ..SyscallAnomalyConfig::default()
+        };
+        let anomaly_log = tmp.path().join(&anomaly_config.log_path);
+        let detector =
+            SyscallAnomalyDetector::new(anomaly_config, tmp.path(), Some(logger.clone()));
+        detector.inspect_correlated_output(
+            Some("run-1"),
+            "sh x.sh",
+            "",
+            "audit: type=1326 syscall=ptrace denied",
+            Some(1),
+        );
+
+        let replay = SessionReplay::build(&logger, Some(&anomaly_log), "run-1")?;
+        let kinds: Vec<TimelineEntryKind> = replay.entries.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds[..2],
+            [TimelineEntryKind::Command, TimelineEntryKind::Output]
+        );
+        assert!(kinds.contains(&TimelineEntryKind::SecurityEvent));
+        assert!(kinds.contains(&TimelineEntryKind::SyscallAnomaly));
+        assert!(replay
+            .entries
+            .windows(2)
+            .all(|pair| pair[0].timestamp <= pair[1].timestamp));
+
+        let text = replay.render_text();
+        assert!(text.contains(&format!("Session run-1 — {} entries", kinds.len())));
+        assert!(text.contains("`curl -o x.sh host` (exit 0)"));
+        assert!(text.contains("stdout: saved x.sh"));
+        assert!(!text.contains("`ls`"));
+
+        let json = serde_json::to_value(&replay)?;
+        assert_eq!(json["entries"].as_array().map(Vec::len), Some(kinds.len()));
+        Ok(())
+    }
+}
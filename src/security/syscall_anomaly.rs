@@ -4,7 +4,7 @@
 //! syscall-related telemetry hints (seccomp/audit lines), and raises alerts
 //! when the observed pattern deviates from the configured baseline.
 
-use crate::config::{AuditConfig, SyscallAnomalyConfig};
+use crate::config::SyscallAnomalyConfig;
 use crate::security::audit::{AuditEvent, AuditEventType, AuditLogger};
 use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
@@ -14,7 +14,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
 
 const RATE_WINDOW: Duration = Duration::from_secs(60);
@@ -40,6 +40,9 @@ pub struct SyscallAnomalyAlert {
     pub denied_events_last_minute: u32,
     pub total_events_last_minute: u32,
     pub sample: String,
+    /// Correlation id of the command execution that produced this alert
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -75,19 +78,19 @@ pub struct SyscallAnomalyDetector {
     baseline: HashSet<String>,
     state: Mutex<DetectorState>,
     anomaly_log_path: PathBuf,
-    audit_logger: Option<AuditLogger>,
+    audit_logger: Option<Arc<AuditLogger>>,
 }
 
 impl SyscallAnomalyDetector {
-    /// Build a detector from runtime config.
+    /// Build a detector from runtime config. Alerts are also recorded in
+    /// `audit_logger` when given (the process-wide [`AuditLogger::shared`]).
     pub fn new(
         config: SyscallAnomalyConfig,
         zeroclaw_dir: impl AsRef<Path>,
-        audit_config: AuditConfig,
+        audit_logger: Option<Arc<AuditLogger>>,
     ) -> Self {
         let baseline = normalize_baseline(&config.baseline_syscalls);
         let anomaly_log_path = resolve_log_path(zeroclaw_dir.as_ref(), config.log_path.as_str());
-        let audit_logger = AuditLogger::new(audit_config, zeroclaw_dir.as_ref().to_path_buf()).ok();
 
         Self {
             config,
@@ -107,6 +110,19 @@ impl SyscallAnomalyDetector {
         stdout: &str,
         stderr: &str,
         exit_code: Option<i32>,
+    ) -> Vec<SyscallAnomalyAlert> {
+        self.inspect_correlated_output(None, command, stdout, stderr, exit_code)
+    }
+
+    /// Like [`Self::inspect_command_output`], but tags emitted alerts and
+    /// their audit events with the command execution's correlation id.
+    pub fn inspect_correlated_output(
+        &self,
+        correlation_id: Option<&str>,
+        command: &str,
+        stdout: &str,
+        stderr: &str,
+        exit_code: Option<i32>,
     ) -> Vec<SyscallAnomalyAlert> {
         if !self.config.enabled {
             return Vec::new();
@@ -146,6 +162,7 @@ impl SyscallAnomalyDetector {
                         denied_events_last_minute: denied_count,
                         total_events_last_minute: total_count,
                         sample: truncate_sample(&signal.raw_line),
+                        correlation_id: None,
                     });
                 }
             }
@@ -163,6 +180,7 @@ impl SyscallAnomalyDetector {
                     denied_events_last_minute: denied_count,
                     total_events_last_minute: total_count,
                     sample: truncate_sample(&signal.raw_line),
+                    correlation_id: None,
                 });
             }
         }
@@ -182,6 +200,7 @@ impl SyscallAnomalyDetector {
                 denied_events_last_minute: denied_count,
                 total_events_last_minute: total_count,
                 sample,
+                correlation_id: None,
             });
         }
         if total_count > self.config.max_total_events_per_minute {
@@ -196,6 +215,7 @@ impl SyscallAnomalyDetector {
                 denied_events_last_minute: denied_count,
                 total_events_last_minute: total_count,
                 sample,
+                correlation_id: None,
             });
         }
         // Deduplicate per command inspection call to avoid repeated spam.
@@ -207,7 +227,10 @@ impl SyscallAnomalyDetector {
                 continue;
             }
             if should_emit_alert(&mut state, &self.config, &alert, now) {
-                emit_queue.push(alert);
+                emit_queue.push(SyscallAnomalyAlert {
+                    correlation_id: correlation_id.map(str::to_string),
+                    ..alert
+                });
             }
         }
         drop(state);
@@ -238,10 +261,14 @@ impl SyscallAnomalyDetector {
         if let Some(logger) = &self.audit_logger {
             let mut event = AuditEvent::new(AuditEventType::SecurityEvent)
                 .with_actor("daemon".to_string(), None, None)
-                .with_action(alert.command.clone(), "high".to_string(), true, false)
+                // The command already ran; the alert is about its behavior.
+                .with_action(alert.command.clone(), "high".to_string(), true, true)
                 .with_result(false, exit_code, 0, Some(alert.sample.clone()));
+            event.correlation_id.clone_from(&alert.correlation_id);
             event.security.policy_violation = true;
-            let _ = logger.log(&event);
+            if let Err(error) = logger.log(&event) {
+                tracing::warn!("failed to audit syscall anomaly: {error}");
+            }
         }
     }
 
@@ -272,7 +299,7 @@ fn normalize_syscall_name(name: &str) -> String {
     name.trim().to_ascii_lowercase()
 }
 
-fn resolve_log_path(base_dir: &Path, configured_path: &str) -> PathBuf {
+pub(crate) fn resolve_log_path(base_dir: &Path, configured_path: &str) -> PathBuf {
     let trimmed = configured_path.trim();
     let path = Path::new(trimmed);
     if path.is_absolute() {
@@ -509,11 +536,7 @@ mod tests {
 
     fn detector_with(config: SyscallAnomalyConfig) -> SyscallAnomalyDetector {
         let tmp = tempfile::tempdir().expect("tempdir");
-        let audit = AuditConfig {
-            enabled: false,
-            ..AuditConfig::default()
-        };
-        SyscallAnomalyDetector::new(config, tmp.path(), audit)
+        SyscallAnomalyDetector::new(config, tmp.path(), None)
     }
 
     #[test]
@@ -574,6 +597,66 @@ mod tests {
             .any(|alert| alert.kind == SyscallAnomalyKind::UnknownSyscall));
     }
 
+    #[test]
+    fn correlated_alerts_link_to_audit_event() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let config = SyscallAnomalyConfig {
+            baseline_syscalls: vec!["read".into()],
+            ..SyscallAnomalyConfig::default()
+        };
+        let audit = AuditLogger::new(
+            crate::config::AuditConfig {
+                enabled: true,
+                ..crate::config::AuditConfig::default()
+            },
+            tmp.path().to_path_buf(),
+        )
+        .expect("audit logger");
+        let detector = SyscallAnomalyDetector::new(config, tmp.path(), Some(Arc::new(audit)));
+
+        let alerts = detector.inspect_correlated_output(
+            Some("exec-42"),
+            "curl x",
+            "",
+            "audit: type=1326 syscall=openat denied",
+            Some(1),
+        );
+        assert!(!alerts.is_empty());
+        assert!(alerts
+            .iter()
+            .all(|alert| alert.correlation_id.as_deref() == Some("exec-42")));
+
+        let events = AuditLogger::load(&tmp.path().join("audit.log")).expect("audit log");
+        assert!(events
+            .iter()
+            .all(|event| event.correlation_id.as_deref() == Some("exec-42")));
+    }
+
+    #[test]
+    fn audited_commands_have_configured_secrets_redacted() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let mut root = crate::config::Config::default();
+        root.config_path = tmp.path().join("config.toml");
+        root.api_key = Some("vendor-pass-9999".into());
+        let config = SyscallAnomalyConfig {
+            baseline_syscalls: vec!["read".into()],
+            ..SyscallAnomalyConfig::default()
+        };
+        let audit = AuditLogger::shared(&root).expect("audit logger");
+        let detector = SyscallAnomalyDetector::new(config, tmp.path(), Some(audit));
+
+        let alerts = detector.inspect_command_output(
+            "curl -H x-api-key:vendor-pass-9999 host",
+            "",
+            "audit: type=1326 syscall=openat denied",
+            Some(1),
+        );
+        assert!(!alerts.is_empty());
+        let raw = std::fs::read_to_string(tmp.path().join("audit.log")).expect("audit log");
+        assert!(raw.contains("curl -H"));
+        assert!(!raw.contains("vendor-pass-9999"));
+    }
+
     #[test]
     fn detector_alerts_on_denied_rate_spike() {
         let config = SyscallAnomalyConfig {
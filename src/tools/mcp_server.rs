@@ -0,0 +1,476 @@
+//! MCP (Model Context Protocol) server mode over stdio.
+//!
+//! Exposes the guarded default tools (shell, file, search, and optionally
+//! `http_request`) to external MCP clients. Every `tools/call` runs through
+//! the same `SecurityPolicy`-enforcing tool implementations the agent uses
+//! and is recorded in the audit log, so agent frameworks can use zeroclaw as
+//! a drop-in safety layer.
+//!
+//! MCP clients are treated as a non-interactive channel: calls that need
+//! approval under the autonomy config are denied, and clients cannot
+//! self-approve risky commands through an `approved` argument.
+
+use super::mcp_protocol::{
+    JsonRpcError, JsonRpcRequest, JsonRpcResponse, McpToolDef, INTERNAL_ERROR, INVALID_PARAMS,
+    INVALID_REQUEST, JSONRPC_VERSION, MCP_PROTOCOL_VERSION, METHOD_NOT_FOUND, PARSE_ERROR,
+};
+use super::shell::extract_command_argument;
+use super::traits::Tool;
+use super::HttpRequestTool;
+use crate::approval::{ApprovalManager, ApprovalResponse};
+use crate::config::Config;
+use crate::runtime::{self, RuntimeAdapter};
+use crate::security::audit::{AuditEvent, AuditEventType, AuditLogger};
+use crate::security::SecurityPolicy;
+use anyhow::Result;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+/// Audit channel name recorded for calls made through the MCP server.
+const MCP_AUDIT_CHANNEL: &str = "mcp";
+
+/// Argument through which tools accept explicit approval of risky
+/// operations. Only the approval flow may grant it, never the MCP client.
+const APPROVED_ARGUMENT: &str = "approved";
+
+/// MCP server dispatching JSON-RPC requests to zeroclaw tools.
+pub struct McpServer {
+    tools: Vec<Box<dyn Tool>>,
+    audit: Option<Arc<AuditLogger>>,
+    approval: Option<ApprovalManager>,
+    security: Option<Arc<SecurityPolicy>>,
+}
+
+impl McpServer {
+    pub fn new(tools: Vec<Box<dyn Tool>>) -> Self {
+        Self {
+            tools,
+            audit: None,
+            approval: None,
+            security: None,
+        }
+    }
+
+    /// Record every tool call in `audit`.
+    pub fn with_audit(mut self, audit: Arc<AuditLogger>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// Deny calls that `approval` says need interactive approval.
+    pub fn with_approval(mut self, approval: ApprovalManager) -> Self {
+        self.approval = Some(approval);
+        self
+    }
+
+    /// Check shell commands against `security` before running them, so
+    /// policy rejections are reported (and audited) as denials rather than
+    /// ordinary tool failures.
+    pub fn with_security(mut self, security: Arc<SecurityPolicy>) -> Self {
+        self.security = Some(security);
+        self
+    }
+
+    /// Serve newline-delimited JSON-RPC on stdin/stdout until stdin closes.
+    pub async fn serve_stdio(&self) -> Result<()> {
+        let mut lines = BufReader::new(tokio::io::stdin()).lines();
+        let mut stdout = tokio::io::stdout();
+
+        while let Some(line) = lines.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(response) = self.handle_line(&line).await {
+                let mut encoded = serde_json::to_string(&response)?;
+                encoded.push('\n');
+                stdout.write_all(encoded.as_bytes()).await?;
+                stdout.flush().await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle one raw JSON-RPC message. Notifications produce no response.
+    pub async fn handle_line(&self, line: &str) -> Option<JsonRpcResponse> {
+        match serde_json::from_str::<JsonRpcRequest>(line) {
+            Ok(request) => self.handle_request(request).await,
+            Err(e) => Some(error_response(
+                None,
+                PARSE_ERROR,
+                format!("Invalid JSON-RPC message: {e}"),
+            )),
+        }
+    }
+
+    pub async fn handle_request(&self, request: JsonRpcRequest) -> Option<JsonRpcResponse> {
+        if request.jsonrpc != JSONRPC_VERSION {
+            return Some(error_response(
+                request.id,
+                INVALID_REQUEST,
+                format!("Unsupported jsonrpc version: {}", request.jsonrpc),
+            ));
+        }
+        // Notifications (no id) never get a response, even on error.
+        let id = request.id?;
+        let params = request.params.unwrap_or(Value::Null);
+
+        let result = match request.method.as_str() {
+            "initialize" => Ok(json!({
+                "protocolVersion": MCP_PROTOCOL_VERSION,
+                "capabilities": { "tools": {} },
+                "serverInfo": {
+                    "name": "zeroclaw",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+            })),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": self.tool_defs() })),
+            "tools/call" => self.call_tool(&params).await,
+            other => Err((METHOD_NOT_FOUND, format!("Method not found: {other}"))),
+        };
+
+        Some(match result {
+            Ok(result) => JsonRpcResponse {
+                jsonrpc: JSONRPC_VERSION.to_string(),
+                id: Some(id),
+                result: Some(result),
+                error: None,
+            },
+            Err((code, message)) => error_response(Some(id), code, message),
+        })
+    }
+
+    fn tool_defs(&self) -> Vec<McpToolDef> {
+        self.tools
+            .iter()
+            .map(|tool| {
+                let mut input_schema = tool.parameters_schema();
+                if let Some(properties) = input_schema
+                    .get_mut("properties")
+                    .and_then(Value::as_object_mut)
+                {
+                    properties.remove(APPROVED_ARGUMENT);
+                }
+                McpToolDef {
+                    name: tool.name().to_string(),
+                    description: Some(tool.description().to_string()),
+                    input_schema,
+                }
+            })
+            .collect()
+    }
+
+    /// Reason the call must not run, if approval or policy rejects it.
+    fn denial(&self, name: &str, arguments: &Value) -> Option<String> {
+        if let Some(approval) = &self.approval {
+            if approval.needs_approval(name) {
+                approval.record_decision(name, arguments, ApprovalResponse::No, MCP_AUDIT_CHANNEL);
+                return Some(format!(
+                    "Denied: {name} requires interactive approval, which MCP clients cannot give"
+                ));
+            }
+        }
+        if name == "shell" {
+            let security = self.security.as_ref()?;
+            let command = extract_command_argument(arguments)?;
+            if let Err(reason) = security.validate_command_execution(&command, false) {
+                return Some(reason);
+            }
+        }
+        None
+    }
+
+    async fn call_tool(&self, params: &Value) -> std::result::Result<Value, (i32, String)> {
+        let name = params.get("name").and_then(Value::as_str).ok_or((
+            INVALID_PARAMS,
+            "tools/call requires a tool name".to_string(),
+        ))?;
+        let mut arguments = params
+            .get("arguments")
+            .cloned()
+            .unwrap_or_else(|| json!({}));
+        if let Some(arguments) = arguments.as_object_mut() {
+            arguments.remove(APPROVED_ARGUMENT);
+        }
+        let tool = self
+            .tools
+            .iter()
+            .find(|tool| tool.name() == name)
+            .ok_or_else(|| (INVALID_PARAMS, format!("Unknown tool: {name}")))?;
+
+        if let Some(reason) = self.denial(name, &arguments) {
+            self.audit_call(name, &arguments, false, false, 0, Some(reason.clone()));
+            return Ok(json!({
+                "content": [{ "type": "text", "text": reason }],
+                "isError": true,
+            }));
+        }
+
+        let started = Instant::now();
+        let outcome = tool.execute(arguments.clone()).await;
+        let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        let (success, error) = match &outcome {
+            Ok(result) => (result.success, result.error.clone()),
+            Err(e) => (false, Some(e.to_string())),
+        };
+        self.audit_call(name, &arguments, true, success, duration_ms, error);
+
+        let result = outcome.map_err(|e| (INTERNAL_ERROR, format!("Tool {name} failed: {e}")))?;
+        let text = match (result.success, result.error) {
+            (false, Some(error)) if result.output.is_empty() => error,
+            (false, Some(error)) => format!("{}\n{error}", result.output),
+            _ => result.output,
+        };
+        Ok(json!({
+            "content": [{ "type": "text", "text": text }],
+            "isError": !result.success,
+        }))
+    }
+
+    fn audit_call(
+        &self,
+        name: &str,
+        arguments: &Value,
+        allowed: bool,
+        success: bool,
+        duration_ms: u64,
+        error: Option<String>,
+    ) {
+        let Some(audit) = self.audit.as_ref() else {
+            return;
+        };
+        let event = AuditEvent::new(AuditEventType::CommandExecution)
+            .with_actor(MCP_AUDIT_CHANNEL.to_string(), None, None)
+            .with_action(
+                format!("{name} {arguments}"),
+                "unknown".to_string(),
+                false,
+                allowed,
+            )
+            .with_result(success, None, duration_ms, error);
+        if let Err(e) = audit.log(&event) {
+            tracing::warn!("Failed to audit MCP tool call: {e}");
+        }
+    }
+}
+
+/// Run `zeroclaw mcp-serve`: expose the configured tools over stdio.
+pub async fn run(config: Config) -> Result<()> {
+    let runtime: Arc<dyn RuntimeAdapter> = Arc::from(runtime::create_runtime(&config.runtime)?);
+    let security = Arc::new(SecurityPolicy::from_config(
+        &config.autonomy,
+        &config.workspace_dir,
+    ));
+
+    let mut tools = super::default_tools_with_runtime(security.clone(), runtime);
+    let http = &config.http_request;
+    if http.enabled {
+        tools.push(Box::new(HttpRequestTool::new(
+            security.clone(),
+            http.allowed_domains.clone(),
+            config.security.url_access.clone(),
+            http.max_response_size,
+            http.timeout_secs,
+            http.user_agent.clone(),
+            http.credential_profiles.clone(),
+        )));
+    }
+
+    let mut server = McpServer::new(tools)
+        .with_approval(ApprovalManager::from_config(&config.autonomy))
+        .with_security(security);
+    if config.security.audit.enabled {
+        server = server.with_audit(AuditLogger::shared(&config)?);
+    }
+
+    tracing::info!("MCP server ready on stdio ({} tools)", server.tools.len());
+    server.serve_stdio().await
+}
+
+fn error_response(id: Option<Value>, code: i32, message: String) -> JsonRpcResponse {
+    JsonRpcResponse {
+        jsonrpc: JSONRPC_VERSION.to_string(),
+        id,
+        result: None,
+        error: Some(JsonRpcError {
+            code,
+            message,
+            data: None,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AuditConfig;
+    use crate::security::audit::AuditQuery;
+    use crate::tools::traits::ToolResult;
+    use async_trait::async_trait;
+    use tempfile::TempDir;
+
+    struct EchoTool;
+
+    #[async_trait]
+    impl Tool for EchoTool {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn description(&self) -> &str {
+            "Echo the value argument"
+        }
+
+        fn parameters_schema(&self) -> Value {
+            json!({ "type": "object", "properties": { "value": { "type": "string" } } })
+        }
+
+        async fn execute(&self, args: Value) -> Result<ToolResult> {
+            match args.get("value").and_then(Value::as_str) {
+                Some(value) => Ok(ToolResult {
+                    success: true,
+                    output: value.to_string(),
+                    error: None,
+                }),
+                None => Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some("blocked by policy".into()),
+                }),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_and_calls_tools() {
+        let server = McpServer::new(vec![Box::new(EchoTool)]);
+
+        let list = server
+            .handle_line(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#)
+            .await
+            .unwrap();
+        assert_eq!(list.result.unwrap()["tools"][0]["name"], "echo");
+
+        let call = server
+            .handle_line(
+                r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"echo","arguments":{"value":"hi"}}}"#,
+            )
+            .await
+            .unwrap();
+        let result = call.result.unwrap();
+        assert_eq!(result["content"][0]["text"], "hi");
+        assert_eq!(result["isError"], false);
+
+        assert!(server
+            .handle_line(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#)
+            .await
+            .is_none());
+        let unknown = server
+            .handle_line(r#"{"jsonrpc":"2.0","id":3,"method":"resources/list"}"#)
+            .await
+            .unwrap();
+        assert_eq!(unknown.error.unwrap().code, METHOD_NOT_FOUND);
+    }
+
+    fn audited_server(
+        tmp: &TempDir,
+        tools: Vec<Box<dyn Tool>>,
+    ) -> Result<(McpServer, Arc<AuditLogger>)> {
+        let audit = Arc::new(AuditLogger::new(
+            AuditConfig {
+                enabled: true,
+                ..AuditConfig::default()
+            },
+            tmp.path().to_path_buf(),
+        )?);
+        Ok((McpServer::new(tools).with_audit(audit.clone()), audit))
+    }
+
+    #[tokio::test]
+    async fn supervised_calls_are_denied_without_self_approval() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let security = Arc::new(SecurityPolicy {
+            workspace_dir: tmp.path().to_path_buf(),
+            ..SecurityPolicy::default()
+        });
+        let runtime: Arc<dyn RuntimeAdapter> = Arc::new(runtime::NativeRuntime::new());
+        let shell: Box<dyn Tool> =
+            Box::new(super::super::ShellTool::new(security.clone(), runtime));
+        let (server, audit) = audited_server(&tmp, vec![shell, Box::new(EchoTool)])?;
+        let server = server
+            .with_approval(ApprovalManager::from_config(
+                &crate::config::AutonomyConfig {
+                    auto_approve: vec!["shell".into()],
+                    ..crate::config::AutonomyConfig::default()
+                },
+            ))
+            .with_security(security);
+
+        let list = server
+            .handle_line(r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#)
+            .await
+            .unwrap();
+        assert!(
+            list.result.unwrap()["tools"][0]["inputSchema"]["properties"]
+                .get("approved")
+                .is_none()
+        );
+
+        // Medium-risk command: the client's `approved` flag must not count.
+        let commit = server
+            .handle_line(
+                r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"shell","arguments":{"command":"git commit -m x","approved":true}}}"#,
+            )
+            .await
+            .unwrap();
+        let result = commit.result.unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("requires explicit approval"));
+
+        // Not auto-approved, and an MCP client cannot be prompted.
+        let echo = server
+            .handle_line(
+                r#"{"jsonrpc":"2.0","id":3,"method":"tools/call","params":{"name":"echo","arguments":{"value":"hi"}}}"#,
+            )
+            .await
+            .unwrap();
+        assert_eq!(echo.result.unwrap()["isError"], true);
+
+        let events = audit.query(&AuditQuery::default())?;
+        assert_eq!(events.len(), 2);
+        assert!(events
+            .iter()
+            .all(|event| !event.action.as_ref().unwrap().allowed));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_calls_are_reported_and_audited() -> Result<()> {
+        let tmp = TempDir::new()?;
+        let (server, audit) = audited_server(&tmp, vec![Box::new(EchoTool)])?;
+
+        let call = server
+            .handle_line(
+                r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"echo","arguments":{}}}"#,
+            )
+            .await
+            .unwrap();
+        let result = call.result.unwrap();
+        assert_eq!(result["isError"], true);
+        assert_eq!(result["content"][0]["text"], "blocked by policy");
+
+        let events = audit.query(&AuditQuery::default())?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].actor.as_ref().unwrap().channel, "mcp");
+        // A failed tool run is a failure, not a policy denial.
+        assert!(events[0].action.as_ref().unwrap().allowed);
+        assert!(!events[0].result.as_ref().unwrap().success);
+        Ok(())
+    }
+}
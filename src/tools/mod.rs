@@ -53,6 +53,7 @@ pub mod http_request;
 pub mod image_info;
 pub mod mcp_client;
 pub mod mcp_protocol;
+pub mod mcp_server;
 pub mod mcp_tool;
 pub mod mcp_transport;
 pub mod memory_forget;
@@ -338,15 +339,12 @@ pub fn all_tools_with_runtime(
 ) -> Vec<Box<dyn Tool>> {
     let has_shell_access = runtime.has_shell_access();
     let has_filesystem_access = runtime.has_filesystem_access();
-    let zeroclaw_dir = root_config
-        .config_path
-        .parent()
-        .map(std::path::PathBuf::from)
-        .unwrap_or_else(|| runtime.storage_path());
+    let zeroclaw_dir = root_config.zeroclaw_dir_or(|| runtime.storage_path());
+    let audit = crate::security::AuditLogger::shared_if_enabled(root_config);
     let syscall_detector = Arc::new(crate::security::SyscallAnomalyDetector::new(
         root_config.security.syscall_anomaly.clone(),
         &zeroclaw_dir,
-        root_config.security.audit.clone(),
+        audit.clone(),
     ));
 
     let mut tool_arcs: Vec<Arc<dyn Tool>> = vec![
@@ -381,16 +379,22 @@ pub fn all_tools_with_runtime(
     ];
 
     if has_shell_access {
-        tool_arcs.push(Arc::new(ShellTool::new_with_syscall_detector(
-            security.clone(),
-            runtime.clone(),
-            Some(syscall_detector.clone()),
-        )));
-        tool_arcs.push(Arc::new(ProcessTool::new_with_syscall_detector(
-            security.clone(),
-            runtime.clone(),
-            Some(syscall_detector),
-        )));
+        tool_arcs.push(Arc::new(
+            ShellTool::new_with_syscall_detector(
+                security.clone(),
+                runtime.clone(),
+                Some(syscall_detector.clone()),
+            )
+            .with_audit(audit.clone()),
+        ));
+        tool_arcs.push(Arc::new(
+            ProcessTool::new_with_syscall_detector(
+                security.clone(),
+                runtime.clone(),
+                Some(syscall_detector),
+            )
+            .with_audit(audit),
+        ));
         tool_arcs.push(Arc::new(GitOperationsTool::new(
             security.clone(),
             workspace_dir.to_path_buf(),
@@ -1,9 +1,10 @@
 use super::shell::collect_allowed_shell_env_vars;
 use super::traits::{Tool, ToolResult};
 use crate::runtime::RuntimeAdapter;
+use crate::security::audit::{CommandAudit, CommandAuditContext};
 use crate::security::policy::ToolOperation;
-use crate::security::SecurityPolicy;
 use crate::security::SyscallAnomalyDetector;
+use crate::security::{AuditLogger, SecurityPolicy};
 use async_trait::async_trait;
 use serde_json::json;
 use std::collections::HashMap;
@@ -27,6 +28,9 @@ struct OutputBuffer {
 struct ProcessEntry {
     id: usize,
     command: String,
+    /// Correlation id of the spawn's audit event, shared by anomaly alerts
+    /// raised from this process's output
+    correlation_id: String,
     pid: u32,
     started_at: Instant,
     child: Mutex<tokio::process::Child>,
@@ -44,6 +48,7 @@ pub struct ProcessTool {
     security: Arc<SecurityPolicy>,
     runtime: Arc<dyn RuntimeAdapter>,
     syscall_detector: Option<Arc<SyscallAnomalyDetector>>,
+    audit: Option<Arc<AuditLogger>>,
     processes: Arc<RwLock<HashMap<usize, ProcessEntry>>>,
     next_id: Mutex<usize>,
 }
@@ -62,12 +67,33 @@ impl ProcessTool {
             security,
             runtime,
             syscall_detector,
+            audit: None,
             processes: Arc::new(RwLock::new(HashMap::new())),
             next_id: Mutex::new(0),
         }
     }
 
-    fn handle_spawn(&self, args: &serde_json::Value) -> anyhow::Result<ToolResult> {
+    /// Record every spawned command in `audit` (the process-wide
+    /// [`AuditLogger::shared`]).
+    pub fn with_audit(mut self, audit: Option<Arc<AuditLogger>>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Refuse `command`, recording the refusal in the audit log.
+    fn reject(&self, command: &str, approved: bool, error: String) -> ToolResult {
+        if let Some(logger) = &self.audit {
+            let risk = self.security.command_risk_level(command);
+            CommandAudit::reject(logger, command, risk.as_str(), approved, &error);
+        }
+        ToolResult {
+            success: false,
+            output: String::new(),
+            error: Some(error),
+        }
+    }
+
+    async fn handle_spawn(&self, args: &serde_json::Value) -> anyhow::Result<ToolResult> {
         if !self.runtime.supports_long_running() {
             return Ok(ToolResult {
                 success: false,
@@ -105,41 +131,38 @@ impl ProcessTool {
         }
 
         // Reuse shell security chain: rate limit → command validation → path check → record.
-        if self.security.is_rate_limited() {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some("Rate limit exceeded: too many actions in the last hour".into()),
-            });
-        }
-
         let approved = args
             .get("approved")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
-        if let Err(reason) = self.security.validate_command_execution(command, approved) {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some(reason),
-            });
+        if self.security.is_rate_limited() {
+            return Ok(self.reject(
+                command,
+                approved,
+                "Rate limit exceeded: too many actions in the last hour".into(),
+            ));
         }
 
+        let risk = match self.security.validate_command_execution(command, approved) {
+            Ok(risk) => risk,
+            Err(reason) => return Ok(self.reject(command, approved, reason)),
+        };
+
         if let Some(path) = self.security.forbidden_path_argument(command) {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some(format!("Path blocked by security policy: {path}")),
-            });
+            return Ok(self.reject(
+                command,
+                approved,
+                format!("Path blocked by security policy: {path}"),
+            ));
         }
 
         if !self.security.record_action() {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some("Rate limit exceeded: action budget exhausted".into()),
-            });
+            return Ok(self.reject(
+                command,
+                approved,
+                "Rate limit exceeded: action budget exhausted".into(),
+            ));
         }
 
         // Build command via runtime adapter.
@@ -168,16 +191,34 @@ impl ProcessTool {
             }
         }
 
+        let audit = self
+            .audit
+            .clone()
+            .map(|logger| CommandAudit::begin(logger, command, risk.as_str(), approved));
+        let correlation_id = audit.as_ref().map_or_else(
+            || CommandAuditContext::current().correlation_id,
+            |audit| audit.correlation_id().to_string(),
+        );
+
         let mut child = match cmd.spawn() {
             Ok(child) => child,
             Err(e) => {
+                let error = format!("Failed to spawn process: {e}");
+                if let Some(audit) = audit {
+                    audit.finish(false, None, Some(error.clone()), None).await;
+                }
                 return Ok(ToolResult {
                     success: false,
                     output: String::new(),
-                    error: Some(format!("Failed to spawn process: {e}")),
+                    error: Some(error),
                 });
             }
         };
+        // Background processes outlive the call, so the event records the
+        // spawn; later output is inspected under the same correlation id.
+        if let Some(audit) = audit {
+            audit.finish(true, None, None, None).await;
+        }
 
         let pid = child.id().unwrap_or(0);
 
@@ -202,6 +243,7 @@ impl ProcessTool {
         let entry = ProcessEntry {
             id,
             command: command.to_string(),
+            correlation_id,
             pid,
             started_at: Instant::now(),
             child: Mutex::new(child),
@@ -312,7 +354,8 @@ impl ProcessTool {
             );
 
             if !stdout_delta.is_empty() || !stderr_delta.is_empty() {
-                let _ = detector.inspect_command_output(
+                let _ = detector.inspect_correlated_output(
+                    Some(&entry.correlation_id),
                     &entry.command,
                     stdout_delta,
                     stderr_delta,
@@ -503,7 +546,7 @@ impl Tool for ProcessTool {
         let action = args.get("action").and_then(|v| v.as_str()).unwrap_or("");
 
         match action {
-            "spawn" => self.handle_spawn(&args),
+            "spawn" => self.handle_spawn(&args).await,
             "list" => self.handle_list(),
             "output" => self.handle_output(&args),
             "kill" => self.handle_kill(&args),
@@ -533,7 +576,7 @@ impl Drop for ProcessTool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{AuditConfig, SyscallAnomalyConfig};
+    use crate::config::SyscallAnomalyConfig;
     use crate::runtime::NativeRuntime;
     use crate::security::{AutonomyLevel, SecurityPolicy, SyscallAnomalyDetector};
     use std::path::PathBuf;
@@ -560,11 +603,7 @@ mod tests {
             max_alerts_per_minute: 50,
             ..SyscallAnomalyConfig::default()
         };
-        let audit = AuditConfig {
-            enabled: false,
-            ..AuditConfig::default()
-        };
-        Arc::new(SyscallAnomalyDetector::new(cfg, tmp.path(), audit))
+        Arc::new(SyscallAnomalyDetector::new(cfg, tmp.path(), None))
     }
 
     fn make_tool() -> ProcessTool {
@@ -902,4 +941,73 @@ mod tests {
             "incremental offsets should prevent duplicate detector emissions for unchanged output"
         );
     }
+
+    #[tokio::test]
+    async fn spawn_is_audited_and_later_alerts_share_its_correlation_id() {
+        use crate::config::AuditConfig;
+        use crate::security::audit::{scope_command_context, AuditEventType, AuditQuery};
+
+        let tmp = TempDir::new().expect("temp dir should be created");
+        let audit = Arc::new(
+            AuditLogger::new(
+                AuditConfig {
+                    enabled: true,
+                    ..AuditConfig::default()
+                },
+                tmp.path().to_path_buf(),
+            )
+            .unwrap(),
+        );
+        let detector = Arc::new(SyscallAnomalyDetector::new(
+            SyscallAnomalyConfig {
+                baseline_syscalls: vec!["read".into(), "write".into()],
+                ..SyscallAnomalyConfig::default()
+            },
+            tmp.path(),
+            Some(audit.clone()),
+        ));
+        let tool =
+            ProcessTool::new_with_syscall_detector(test_security(), test_runtime(), Some(detector))
+                .with_audit(Some(audit.clone()));
+
+        let context = CommandAuditContext {
+            channel: "cli".into(),
+            correlation_id: "turn-7".into(),
+        };
+        let spawn_result = scope_command_context(
+            context,
+            tool.execute(json!({
+                "action": "spawn",
+                "command": "echo seccomp denied syscall=openat"
+            })),
+        )
+        .await
+        .expect("spawn should return result");
+        assert!(spawn_result.success);
+        let spawn_output: serde_json::Value =
+            serde_json::from_str(&spawn_result.output).expect("spawn output should be json");
+        let id = spawn_output["id"]
+            .as_u64()
+            .expect("process id should exist");
+
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+        // Polled from a later turn: alerts still point at the spawn.
+        tool.execute(json!({"action": "output", "id": id}))
+            .await
+            .expect("output should return result");
+
+        let events = audit.query(&AuditQuery::default()).unwrap();
+        let spawn = events
+            .iter()
+            .find(|event| event.event_type == AuditEventType::CommandExecution)
+            .expect("spawn should be audited");
+        assert_eq!(spawn.correlation_id.as_deref(), Some("turn-7"));
+        assert!(spawn.result.as_ref().unwrap().success);
+        let alert = events
+            .iter()
+            .find(|event| event.event_type == AuditEventType::SecurityEvent)
+            .expect("anomaly alert should be audited");
+        assert_eq!(alert.correlation_id.as_deref(), Some("turn-7"));
+    }
 }
@@ -1,7 +1,8 @@
 use super::traits::{Tool, ToolResult};
 use crate::runtime::RuntimeAdapter;
-use crate::security::SecurityPolicy;
+use crate::security::audit::{CommandAudit, CommandAuditContext};
 use crate::security::SyscallAnomalyDetector;
+use crate::security::{AuditLogger, SecurityPolicy};
 use async_trait::async_trait;
 use serde_json::json;
 use std::collections::HashSet;
@@ -53,6 +54,7 @@ pub struct ShellTool {
     security: Arc<SecurityPolicy>,
     runtime: Arc<dyn RuntimeAdapter>,
     syscall_detector: Option<Arc<SyscallAnomalyDetector>>,
+    audit: Option<Arc<AuditLogger>>,
 }
 
 impl ShellTool {
@@ -69,6 +71,27 @@ impl ShellTool {
             security,
             runtime,
             syscall_detector,
+            audit: None,
+        }
+    }
+
+    /// Record every executed command, with its output and file changes, in
+    /// `audit` (the process-wide [`AuditLogger::shared`]).
+    pub fn with_audit(mut self, audit: Option<Arc<AuditLogger>>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Refuse `command`, recording the refusal in the audit log.
+    fn reject(&self, command: &str, approved: bool, error: String) -> ToolResult {
+        if let Some(logger) = &self.audit {
+            let risk = self.security.command_risk_level(command);
+            CommandAudit::reject(logger, command, risk.as_str(), approved, &error);
+        }
+        ToolResult {
+            success: false,
+            output: String::new(),
+            error: Some(error),
         }
     }
 }
@@ -101,7 +124,7 @@ pub(super) fn collect_allowed_shell_env_vars(security: &SecurityPolicy) -> Vec<S
     out
 }
 
-fn extract_command_argument(args: &serde_json::Value) -> Option<String> {
+pub(crate) fn extract_command_argument(args: &serde_json::Value) -> Option<String> {
     if let Some(command) = args
         .get("command")
         .and_then(|v| v.as_str())
@@ -174,38 +197,32 @@ impl Tool for ShellTool {
             .unwrap_or(false);
 
         if self.security.is_rate_limited() {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some("Rate limit exceeded: too many actions in the last hour".into()),
-            });
+            return Ok(self.reject(
+                &command,
+                approved,
+                "Rate limit exceeded: too many actions in the last hour".into(),
+            ));
         }
 
-        match self.security.validate_command_execution(&command, approved) {
-            Ok(_) => {}
-            Err(reason) => {
-                return Ok(ToolResult {
-                    success: false,
-                    output: String::new(),
-                    error: Some(reason),
-                });
-            }
-        }
+        let risk = match self.security.validate_command_execution(&command, approved) {
+            Ok(risk) => risk,
+            Err(reason) => return Ok(self.reject(&command, approved, reason)),
+        };
 
         if let Some(path) = self.security.forbidden_path_argument(&command) {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some(format!("Path blocked by security policy: {path}")),
-            });
+            return Ok(self.reject(
+                &command,
+                approved,
+                format!("Path blocked by security policy: {path}"),
+            ));
         }
 
         if !self.security.record_action() {
-            return Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some("Rate limit exceeded: action budget exhausted".into()),
-            });
+            return Ok(self.reject(
+                &command,
+                approved,
+                "Rate limit exceeded: action budget exhausted".into(),
+            ));
         }
 
         // Execute with timeout to prevent hanging commands.
@@ -232,6 +249,19 @@ impl Tool for ShellTool {
             }
         }
 
+        let audit = match self.audit.clone() {
+            Some(logger) => Some(
+                CommandAudit::begin(logger, &command, risk.as_str(), approved)
+                    .track_file_changes(&self.security.workspace_dir)
+                    .await,
+            ),
+            None => None,
+        };
+        let correlation_id = audit.as_ref().map_or_else(
+            || CommandAuditContext::current().correlation_id,
+            |audit| audit.correlation_id().to_string(),
+        );
+
         let result =
             tokio::time::timeout(Duration::from_secs(SHELL_TIMEOUT_SECS), cmd.output()).await;
 
@@ -251,7 +281,8 @@ impl Tool for ShellTool {
                 }
 
                 if let Some(detector) = &self.syscall_detector {
-                    let _ = detector.inspect_command_output(
+                    let _ = detector.inspect_correlated_output(
+                        Some(&correlation_id),
                         &command,
                         &stdout,
                         &stderr,
@@ -259,6 +290,17 @@ impl Tool for ShellTool {
                     );
                 }
 
+                if let Some(audit) = audit {
+                    audit
+                        .finish(
+                            output.status.success(),
+                            output.status.code(),
+                            None,
+                            Some((&stdout, &stderr)),
+                        )
+                        .await;
+                }
+
                 Ok(ToolResult {
                     success: output.status.success(),
                     output: stdout,
@@ -269,18 +311,28 @@ impl Tool for ShellTool {
                     },
                 })
             }
-            Ok(Err(e)) => Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some(format!("Failed to execute command: {e}")),
-            }),
-            Err(_) => Ok(ToolResult {
-                success: false,
-                output: String::new(),
-                error: Some(format!(
-                    "Command timed out after {SHELL_TIMEOUT_SECS}s and was killed"
-                )),
-            }),
+            Ok(Err(e)) => {
+                let error = format!("Failed to execute command: {e}");
+                if let Some(audit) = audit {
+                    audit.finish(false, None, Some(error.clone()), None).await;
+                }
+                Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(error),
+                })
+            }
+            Err(_) => {
+                let error = format!("Command timed out after {SHELL_TIMEOUT_SECS}s and was killed");
+                if let Some(audit) = audit {
+                    audit.finish(false, None, Some(error.clone()), None).await;
+                }
+                Ok(ToolResult {
+                    success: false,
+                    output: String::new(),
+                    error: Some(error),
+                })
+            }
         }
     }
 }
@@ -288,7 +340,7 @@ impl Tool for ShellTool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{AuditConfig, SyscallAnomalyConfig};
+    use crate::config::SyscallAnomalyConfig;
     use crate::runtime::{NativeRuntime, RuntimeAdapter};
     use crate::security::{AutonomyLevel, SecurityPolicy, SyscallAnomalyDetector};
     use tempfile::TempDir;
@@ -314,11 +366,7 @@ mod tests {
             max_alerts_per_minute: 50,
             ..SyscallAnomalyConfig::default()
         };
-        let audit = AuditConfig {
-            enabled: false,
-            ..AuditConfig::default()
-        };
-        Arc::new(SyscallAnomalyDetector::new(cfg, tmp.path(), audit))
+        Arc::new(SyscallAnomalyDetector::new(cfg, tmp.path(), None))
     }
 
     #[test]
@@ -823,4 +871,120 @@ mod tests {
         assert!(log.contains("\"kind\":\"unknown_syscall\""));
         assert!(log.contains("\"syscall\":\"openat\""));
     }
+
+    #[tokio::test]
+    async fn shell_audits_commands_under_the_turn_correlation_id() {
+        use crate::config::AuditConfig;
+        use crate::security::audit::{
+            scope_command_context, AuditEventType, AuditQuery, CommandAuditContext,
+        };
+        use crate::security::file_changes::FileChangeKind;
+
+        let tmp = TempDir::new().expect("temp dir should be created");
+        let workspace = tmp.path().join("workspace");
+        std::fs::create_dir_all(&workspace).unwrap();
+        let audit = Arc::new(
+            AuditLogger::new(
+                AuditConfig {
+                    enabled: true,
+                    capture_output_max_bytes: 1024,
+                    track_file_changes: true,
+                    ..AuditConfig::default()
+                },
+                tmp.path().to_path_buf(),
+            )
+            .unwrap(),
+        );
+        let detector = Arc::new(SyscallAnomalyDetector::new(
+            SyscallAnomalyConfig {
+                baseline_syscalls: vec!["read".into(), "write".into()],
+                ..SyscallAnomalyConfig::default()
+            },
+            tmp.path(),
+            Some(audit.clone()),
+        ));
+        let tool = ShellTool::new_with_syscall_detector(
+            Arc::new(SecurityPolicy {
+                autonomy: AutonomyLevel::Full,
+                workspace_dir: workspace,
+                ..SecurityPolicy::default()
+            }),
+            test_runtime(),
+            Some(detector),
+        )
+        .with_audit(Some(audit.clone()));
+
+        let context = CommandAuditContext {
+            channel: "telegram".into(),
+            correlation_id: "turn-1".into(),
+        };
+        let result = scope_command_context(
+            context,
+            tool.execute(json!({
+                "command": "touch created.txt && echo seccomp denied syscall=openat",
+                "approved": true
+            })),
+        )
+        .await
+        .expect("command execution should return result");
+        assert!(result.success, "{:?}", result.error);
+
+        let events = audit.query(&AuditQuery::default()).unwrap();
+        let command = events
+            .iter()
+            .find(|event| event.event_type == AuditEventType::CommandExecution)
+            .expect("command execution should be audited");
+        assert_eq!(command.correlation_id.as_deref(), Some("turn-1"));
+        assert_eq!(command.actor.as_ref().unwrap().channel, "telegram");
+        assert_eq!(command.result.as_ref().unwrap().exit_code, Some(0));
+        assert!(command.output.as_ref().unwrap().stdout.contains("openat"));
+        let changes = command.file_changes.as_ref().unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "created.txt");
+        assert_eq!(changes[0].kind, FileChangeKind::Created);
+
+        let alert = events
+            .iter()
+            .find(|event| event.event_type == AuditEventType::SecurityEvent)
+            .expect("anomaly alert should be audited");
+        assert_eq!(alert.correlation_id.as_deref(), Some("turn-1"));
+    }
+
+    #[tokio::test]
+    async fn shell_audits_rejected_commands() {
+        use crate::config::AuditConfig;
+        use crate::security::audit::{AuditEventType, AuditQuery};
+
+        let tmp = TempDir::new().expect("temp dir should be created");
+        let audit = Arc::new(
+            AuditLogger::new(
+                AuditConfig {
+                    enabled: true,
+                    ..AuditConfig::default()
+                },
+                tmp.path().to_path_buf(),
+            )
+            .unwrap(),
+        );
+        let tool = ShellTool::new(test_security(AutonomyLevel::Supervised), test_runtime())
+            .with_audit(Some(audit.clone()));
+
+        let result = tool
+            .execute(json!({"command": "rm -rf /"}))
+            .await
+            .expect("disallowed command execution should return a result");
+        assert!(!result.success);
+
+        let events = audit.query(&AuditQuery::default()).unwrap();
+        let event = events
+            .iter()
+            .find(|event| event.event_type == AuditEventType::CommandExecution)
+            .expect("rejected command should be audited");
+        let action = event.action.as_ref().unwrap();
+        assert_eq!(action.command.as_deref(), Some("rm -rf /"));
+        assert!(!action.allowed);
+        let outcome = event.result.as_ref().unwrap();
+        assert!(!outcome.success);
+        assert_eq!(outcome.error, result.error);
+    }
 }